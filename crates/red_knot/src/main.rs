@@ -74,6 +74,12 @@ to resolve type information for the project's third-party dependencies.",
     )]
     target_version: Option<TargetVersion>,
 
+    #[arg(
+        long,
+        help = "Also honor ruff-style `# noqa` comments as type check suppressions"
+    )]
+    respect_noqa: bool,
+
     #[clap(flatten)]
     verbosity: Verbosity,
 
@@ -93,6 +99,10 @@ impl Args {
             configuration.target_version = Some(target_version.into());
         }
 
+        if self.respect_noqa {
+            configuration.respect_noqa = Some(true);
+        }
+
         if let Some(venv_path) = &self.venv_path {
             configuration.search_paths.site_packages = Some(SitePackages::Derived {
                 venv_path: SystemPath::absolute(venv_path, cli_cwd),
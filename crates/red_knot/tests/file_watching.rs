@@ -240,6 +240,7 @@ where
 
     let configuration = Configuration {
         target_version: Some(PythonVersion::PY312),
+        respect_noqa: None,
         search_paths,
     };
 
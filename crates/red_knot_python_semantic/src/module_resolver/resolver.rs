@@ -1291,6 +1291,7 @@ mod tests {
             &db,
             &ProgramSettings {
                 target_version: PythonVersion::PY38,
+                respect_noqa: false,
                 search_paths: SearchPathSettings {
                     extra_paths: vec![],
                     src_root: src.clone(),
@@ -1796,6 +1797,7 @@ not_a_directory
             &db,
             &ProgramSettings {
                 target_version: PythonVersion::default(),
+                respect_noqa: false,
                 search_paths: SearchPathSettings {
                     extra_paths: vec![],
                     src_root: SystemPathBuf::from("/src"),
@@ -227,6 +227,7 @@ impl TestCaseBuilder<MockedTypeshed> {
             &db,
             &ProgramSettings {
                 target_version,
+                respect_noqa: false,
                 search_paths: SearchPathSettings {
                     extra_paths: vec![],
                     src_root: src.clone(),
@@ -283,6 +284,7 @@ impl TestCaseBuilder<VendoredTypeshed> {
             &db,
             &ProgramSettings {
                 target_version,
+                respect_noqa: false,
                 search_paths: SearchPathSettings {
                     site_packages: SitePackages::Known(vec![site_packages.clone()]),
                     ..SearchPathSettings::new(src.clone())
@@ -12,6 +12,9 @@ use crate::Db;
 pub struct Program {
     pub target_version: PythonVersion,
 
+    /// Whether a ruff-style `# noqa` comment also suppresses type check diagnostics.
+    pub respect_noqa: bool,
+
     #[return_ref]
     pub(crate) search_paths: SearchPaths,
 }
@@ -20,6 +23,7 @@ impl Program {
     pub fn from_settings(db: &dyn Db, settings: &ProgramSettings) -> anyhow::Result<Self> {
         let ProgramSettings {
             target_version,
+            respect_noqa,
             search_paths,
         } = settings;
 
@@ -28,9 +32,11 @@ impl Program {
         let search_paths = SearchPaths::from_settings(db, search_paths)
             .with_context(|| "Invalid search path settings")?;
 
-        Ok(Program::builder(settings.target_version, search_paths)
-            .durability(Durability::HIGH)
-            .new(db))
+        Ok(
+            Program::builder(settings.target_version, *respect_noqa, search_paths)
+                .durability(Durability::HIGH)
+                .new(db),
+        )
     }
 
     pub fn update_search_paths(
@@ -57,6 +63,8 @@ impl Program {
 #[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct ProgramSettings {
     pub target_version: PythonVersion,
+    /// Whether a ruff-style `# noqa` comment also suppresses type check diagnostics.
+    pub respect_noqa: bool,
     pub search_paths: SearchPathSettings,
 }
 
@@ -23,10 +23,10 @@ use crate::semantic_index::symbol::{
     FileScopeId, NodeWithScopeKey, NodeWithScopeRef, Scope, ScopeId, ScopedSymbolId,
     SymbolTableBuilder,
 };
-use crate::semantic_index::use_def::{FlowSnapshot, UseDefMapBuilder};
+use crate::semantic_index::use_def::{FlowSnapshot, UnboundReason, UseDefMapBuilder};
 use crate::semantic_index::SemanticIndex;
 use crate::unpack::Unpack;
-use crate::Db;
+use crate::{Db, Program};
 
 use super::constraint::{Constraint, ConstraintNode, PatternConstraint};
 use super::definition::{
@@ -175,8 +175,10 @@ impl<'db> SemanticIndexBuilder<'db> {
         self.current_use_def_map_mut().restore(state);
     }
 
-    fn flow_merge(&mut self, state: FlowSnapshot) {
-        self.current_use_def_map_mut().merge(state);
+    /// Merge `state` into the current flow state; `reason` is the kind of control flow
+    /// construct whose paths are being joined, used to attribute possible unboundness.
+    fn flow_merge(&mut self, state: FlowSnapshot, reason: UnboundReason) {
+        self.current_use_def_map_mut().merge(state, reason);
     }
 
     fn add_symbol(&mut self, name: Name) -> ScopedSymbolId {
@@ -272,6 +274,142 @@ impl<'db> SemanticIndexBuilder<'db> {
             });
     }
 
+    /// Statically evaluate a comparison of `sys.version_info` against a tuple of integer
+    /// literals, as used by typeshed to guard version-dependent definitions.
+    ///
+    /// Returns `None` for anything other than a simple comparison of `sys.version_info`
+    /// with a one- or two-element tuple of integer literals; such tests get regular
+    /// flow-sensitive handling. This is a purely syntactic check (the semantic index is
+    /// built before any type inference runs), so only the common `sys.version_info`
+    /// spelling is recognized, not aliases of it.
+    fn evaluate_version_guard(&self, test: &ast::Expr) -> Option<bool> {
+        fn int_tuple(expr: &ast::Expr) -> Option<Vec<u64>> {
+            let ast::Expr::Tuple(tuple) = expr else {
+                return None;
+            };
+            // Longer tuples compare against the `micro`/`releaselevel`/`serial` fields,
+            // which the target version doesn't model.
+            if tuple.elts.len() > 2 {
+                return None;
+            }
+            tuple
+                .elts
+                .iter()
+                .map(|element| {
+                    let ast::Expr::NumberLiteral(number) = element else {
+                        return None;
+                    };
+                    let ast::Number::Int(int) = &number.value else {
+                        return None;
+                    };
+                    int.as_u64()
+                })
+                .collect()
+        }
+
+        fn is_version_info(expr: &ast::Expr) -> bool {
+            let ast::Expr::Attribute(ast::ExprAttribute { value, attr, .. }) = expr else {
+                return false;
+            };
+            attr == "version_info" && matches!(&**value, ast::Expr::Name(name) if name.id == "sys")
+        }
+
+        let ast::Expr::Compare(ast::ExprCompare {
+            left,
+            ops,
+            comparators,
+            ..
+        }) = test
+        else {
+            return None;
+        };
+        let ([op], [comparator]) = (&**ops, &**comparators) else {
+            return None;
+        };
+
+        let (op, literal) = if is_version_info(left) {
+            (*op, int_tuple(comparator)?)
+        } else if is_version_info(comparator) {
+            // A mirrored comparison like `(3, 8) <= sys.version_info`.
+            let mirrored = match op {
+                ast::CmpOp::Lt => ast::CmpOp::Gt,
+                ast::CmpOp::LtE => ast::CmpOp::GtE,
+                ast::CmpOp::Gt => ast::CmpOp::Lt,
+                ast::CmpOp::GtE => ast::CmpOp::LtE,
+                ast::CmpOp::Eq | ast::CmpOp::NotEq => *op,
+                _ => return None,
+            };
+            (mirrored, int_tuple(left)?)
+        } else {
+            return None;
+        };
+
+        let target_version = Program::get(self.db).target_version(self.db);
+        let version = [
+            u64::from(target_version.major),
+            u64::from(target_version.minor),
+        ];
+        // Compare the way Python compares tuples: element-wise, with a shorter tuple
+        // ordering before any longer tuple it is a prefix of.
+        let ordering = version.iter().cmp(literal.iter());
+        match op {
+            ast::CmpOp::Eq => Some(ordering.is_eq()),
+            ast::CmpOp::NotEq => Some(ordering.is_ne()),
+            ast::CmpOp::Lt => Some(ordering.is_lt()),
+            ast::CmpOp::LtE => Some(ordering.is_le()),
+            ast::CmpOp::Gt => Some(ordering.is_gt()),
+            ast::CmpOp::GtE => Some(ordering.is_ge()),
+            _ => None,
+        }
+    }
+
+    /// Statically evaluate a `TYPE_CHECKING` guard, which is always true from the type
+    /// checker's point of view (and always false at runtime): the guarded branch is the
+    /// one that contributes visible definitions, the opposite of runtime behavior.
+    ///
+    /// Recognizes the conventional spellings `TYPE_CHECKING`, `typing.TYPE_CHECKING` and
+    /// `typing_extensions.TYPE_CHECKING`, as well as their negations with `not`. Like
+    /// [`evaluate_version_guard`](Self::evaluate_version_guard) this is a purely
+    /// syntactic check, so other aliases of the constant are not recognized.
+    fn evaluate_type_checking_guard(test: &ast::Expr) -> Option<bool> {
+        fn is_type_checking(expr: &ast::Expr) -> bool {
+            match expr {
+                ast::Expr::Name(name) => name.id == "TYPE_CHECKING",
+                ast::Expr::Attribute(ast::ExprAttribute { value, attr, .. }) => {
+                    attr == "TYPE_CHECKING"
+                        && matches!(
+                            &**value,
+                            ast::Expr::Name(name)
+                                if name.id == "typing" || name.id == "typing_extensions"
+                        )
+                }
+                _ => false,
+            }
+        }
+
+        match test {
+            ast::Expr::UnaryOp(ast::ExprUnaryOp {
+                op: ast::UnaryOp::Not,
+                operand,
+                ..
+            }) => Self::evaluate_type_checking_guard(operand).map(|value| !value),
+            _ => is_type_checking(test).then_some(true),
+        }
+    }
+
+    /// Statically evaluate an `if` test whose outcome is fixed from the type checker's
+    /// point of view, so that only the selected branch contributes visible definitions:
+    /// `TYPE_CHECKING` guards in any file and, in stub files, `sys.version_info` guards.
+    fn evaluate_static_test(&self, test: &ast::Expr) -> Option<bool> {
+        Self::evaluate_type_checking_guard(test).or_else(|| {
+            if self.file.is_stub(self.db.upcast()) {
+                self.evaluate_version_guard(test)
+            } else {
+                None
+            }
+        })
+    }
+
     fn push_assignment(&mut self, assignment: CurrentAssignment<'db>) {
         self.current_assignments.push(assignment);
     }
@@ -717,35 +855,60 @@ where
                 let pre_if = self.flow_snapshot();
                 let constraint = self.record_expression_constraint(&node.test);
                 let mut constraints = vec![constraint];
+                // `TYPE_CHECKING` guards (and, in stub files, `sys.version_info` guards)
+                // select between alternative definitions rather than describing runtime
+                // control flow; evaluate them statically so that only the selected branch
+                // contributes visible definitions.
+                let static_test = self.evaluate_static_test(&node.test);
+                // whether an earlier branch of the chain is statically known to be taken
+                let mut branch_taken = static_test == Some(true);
+                let mut clause_is_live = static_test != Some(false);
                 self.visit_body(&node.body);
                 let mut post_clauses: Vec<FlowSnapshot> = vec![];
                 for clause in &node.elif_else_clauses {
                     // snapshot after every block except the last; the last one will just become
-                    // the state that we merge the other snapshots into
-                    post_clauses.push(self.flow_snapshot());
+                    // the state that we merge the other snapshots into (a statically dead
+                    // block contributes no state at all)
+                    if clause_is_live {
+                        post_clauses.push(self.flow_snapshot());
+                    }
                     // we can only take an elif/else branch if none of the previous ones were
                     // taken, so the block entry state is always `pre_if`
                     self.flow_restore(pre_if.clone());
                     for constraint in &constraints {
                         self.record_negated_constraint(*constraint);
                     }
+                    clause_is_live = !branch_taken;
                     if let Some(elif_test) = &clause.test {
                         self.visit_expr(elif_test);
                         constraints.push(self.record_expression_constraint(elif_test));
+                        match self.evaluate_static_test(elif_test) {
+                            Some(true) => branch_taken = true,
+                            Some(false) => clause_is_live = false,
+                            None => {}
+                        }
                     }
                     self.visit_body(&clause.body);
                 }
+                if !clause_is_live {
+                    // the final block is statically dead: discard its state in favor of the
+                    // first live one (or the pre-if state, if no branch is ever taken)
+                    let mut live_clauses = post_clauses.into_iter();
+                    let first_live = live_clauses.next().unwrap_or_else(|| pre_if.clone());
+                    self.flow_restore(first_live);
+                    post_clauses = live_clauses.collect();
+                }
                 for post_clause_state in post_clauses {
-                    self.flow_merge(post_clause_state);
+                    self.flow_merge(post_clause_state, UnboundReason::Conditional);
                 }
                 let has_else = node
                     .elif_else_clauses
                     .last()
                     .is_some_and(|clause| clause.test.is_none());
-                if !has_else {
+                if !has_else && !branch_taken {
                     // if there's no else clause, then it's possible we took none of the branches,
                     // and the pre_if state can reach here
-                    self.flow_merge(pre_if);
+                    self.flow_merge(pre_if, UnboundReason::Conditional);
                 }
             }
             ast::Stmt::While(ast::StmtWhile {
@@ -772,13 +935,13 @@ where
 
                 // We may execute the `else` clause without ever executing the body, so merge in
                 // the pre-loop state before visiting `else`.
-                self.flow_merge(pre_loop);
+                self.flow_merge(pre_loop, UnboundReason::Loop);
                 self.visit_body(orelse);
 
                 // Breaking out of a while loop bypasses the `else` clause, so merge in the break
                 // states after visiting `else`.
                 for break_state in break_states {
-                    self.flow_merge(break_state);
+                    self.flow_merge(break_state, UnboundReason::Loop);
                 }
             }
             ast::Stmt::With(ast::StmtWith {
@@ -836,13 +999,13 @@ where
 
                 // We may execute the `else` clause without ever executing the body, so merge in
                 // the pre-loop state before visiting `else`.
-                self.flow_merge(pre_loop);
+                self.flow_merge(pre_loop, UnboundReason::Loop);
                 self.visit_body(orelse);
 
                 // Breaking out of a `for` loop bypasses the `else` clause, so merge in the break
                 // states after visiting `else`.
                 for break_state in break_states {
-                    self.flow_merge(break_state);
+                    self.flow_merge(break_state, UnboundReason::Loop);
                 }
             }
             ast::Stmt::Match(ast::StmtMatch {
@@ -868,13 +1031,13 @@ where
                     self.visit_match_case(case);
                 }
                 for post_clause_state in post_case_snapshots {
-                    self.flow_merge(post_clause_state);
+                    self.flow_merge(post_clause_state, UnboundReason::Conditional);
                 }
                 if !cases
                     .last()
                     .is_some_and(|case| case.guard.is_none() && case.pattern.is_wildcard())
                 {
-                    self.flow_merge(after_subject);
+                    self.flow_merge(after_subject, UnboundReason::Conditional);
                 }
             }
             ast::Stmt::Try(ast::StmtTry {
@@ -916,7 +1079,7 @@ where
                     // Prepare for visiting the `except` block(s)
                     self.flow_restore(pre_try_block_state);
                     for state in try_block_snapshots {
-                        self.flow_merge(state);
+                        self.flow_merge(state, UnboundReason::Try);
                     }
 
                     let pre_except_state = self.flow_snapshot();
@@ -970,7 +1133,7 @@ where
                 self.visit_body(orelse);
 
                 for post_except_state in post_except_states {
-                    self.flow_merge(post_except_state);
+                    self.flow_merge(post_except_state, UnboundReason::Try);
                 }
 
                 // TODO: there's lots of complexity here that isn't yet handled by our model.
@@ -1139,7 +1302,7 @@ where
 
                 self.record_negated_constraint(constraint);
                 self.visit_expr(orelse);
-                self.flow_merge(post_body);
+                self.flow_merge(post_body, UnboundReason::Conditional);
             }
             ast::Expr::ListComp(
                 list_comprehension @ ast::ExprListComp {
@@ -1215,7 +1378,7 @@ where
                     }
                 }
                 for snapshot in snapshots {
-                    self.flow_merge(snapshot);
+                    self.flow_merge(snapshot, UnboundReason::Conditional);
                 }
             }
             _ => {
@@ -74,6 +74,28 @@ impl<'db> Definition<'db> {
                 && matches!(&**module.name(), "typing" | "typing_extensions")
         })
     }
+
+    /// Return true if this symbol was defined in the `dataclasses` module
+    pub(crate) fn is_dataclasses_definition(self, db: &'db dyn Db) -> bool {
+        file_to_module(db, self.file(db)).is_some_and(|module| {
+            module.search_path().is_standard_library() && matches!(&**module.name(), "dataclasses")
+        })
+    }
+
+    /// Return true if this symbol was defined in the `functools` module
+    pub(crate) fn is_functools_definition(self, db: &'db dyn Db) -> bool {
+        file_to_module(db, self.file(db)).is_some_and(|module| {
+            module.search_path().is_standard_library() && matches!(&**module.name(), "functools")
+        })
+    }
+
+    /// Return true if this symbol was defined in the `asyncio` package
+    pub(crate) fn is_asyncio_definition(self, db: &'db dyn Db) -> bool {
+        file_to_module(db, self.file(db)).is_some_and(|module| {
+            module.search_path().is_standard_library()
+                && (&**module.name() == "asyncio" || module.name().starts_with("asyncio."))
+        })
+    }
 }
 
 #[derive(Copy, Clone, Debug)]
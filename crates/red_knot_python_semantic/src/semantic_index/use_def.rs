@@ -175,11 +175,11 @@
 //! each symbol in a scope. But actually modeling it this way would unnecessarily increase the
 //! number of [`Definition`]s that Salsa must track. Since "unbound" is special in that all symbols
 //! share it, and it doesn't have any additional per-symbol state, and constraints are irrelevant
-//! to it, we can represent it more efficiently: we use the `may_be_unbound` boolean on the
-//! [`SymbolBindings`] struct. If this flag is `true` for a use of a symbol, it means the symbol
-//! has a path to the use in which it is never bound. If this flag is `false`, it means we've
-//! eliminated the possibility of unbound: every control flow path to the use includes a binding
-//! for this symbol.
+//! to it, we can represent it more efficiently: we use an optional [`UnboundReason`] on the
+//! [`SymbolBindings`] struct. If this is `Some` for a use of a symbol, it means the symbol has a
+//! path to the use in which it is never bound (and the reason records which kind of control flow
+//! construct is responsible for that path). If it is `None`, it means we've eliminated the
+//! possibility of unbound: every control flow path to the use includes a binding for this symbol.
 //!
 //! To build a [`UseDefMap`], the [`UseDefMapBuilder`] is notified of each new use, definition, and
 //! constraint as they are encountered by the
@@ -237,6 +237,58 @@ use super::constraint::Constraint;
 mod bitset;
 mod symbol_state;
 
+/// Why a symbol may be unbound on some control flow path reaching a use.
+///
+/// Recorded when two control flow paths merge and only one of them binds the symbol, so that
+/// diagnostics can name the construct responsible for the unbound path.
+// TODO: `del` statements are not yet modeled in the use-def map; track a `Deleted` reason for
+// them once they are.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) enum UnboundReason {
+    /// The symbol has not been bound at all on some path since the start of the scope.
+    ///
+    /// This is the initial state of every symbol; when a merge point identifies the construct
+    /// responsible for keeping an unbound path alive, it is replaced with a more specific
+    /// reason.
+    ScopeStart,
+
+    /// The symbol is bound only in some branches of an `if`, `match`, conditional expression,
+    /// or short-circuiting boolean operation.
+    Conditional,
+
+    /// The symbol is bound only inside a loop body that may execute zero times.
+    Loop,
+
+    /// The symbol is bound only by statements in a `try` block that may have raised before
+    /// reaching them.
+    Try,
+
+    /// The symbol is bound only by imports in a `try` block that may have failed.
+    ///
+    /// The use-def map itself never produces this reason (it doesn't inspect what kind of
+    /// definition a binding is); it is refined from [`Try`](Self::Try) at diagnostic time.
+    TryImport,
+}
+
+impl UnboundReason {
+    /// Combine the unbound reasons of two merging control flow paths.
+    ///
+    /// If only one path may be unbound, the construct performing the merge (`join`) is what
+    /// keeps the unbound possibility alive, so it replaces the unspecific
+    /// [`ScopeStart`](Self::ScopeStart); a reason already attributed to an inner construct is
+    /// kept. If both paths may be unbound, the joining construct isn't responsible, and an
+    /// existing specific reason survives.
+    pub(super) fn merge(a: Option<Self>, b: Option<Self>, join: Self) -> Option<Self> {
+        match (a, b) {
+            (None, None) => None,
+            (Some(Self::ScopeStart), None) | (None, Some(Self::ScopeStart)) => Some(join),
+            (Some(Self::ScopeStart), Some(reason)) | (Some(reason), _) | (None, Some(reason)) => {
+                Some(reason)
+            }
+        }
+    }
+}
+
 /// Applicable definitions and constraints for every use of a name.
 #[derive(Debug, PartialEq, Eq)]
 pub(crate) struct UseDefMap<'db> {
@@ -283,6 +335,11 @@ impl<'db> UseDefMap<'db> {
         }
     }
 
+    /// Why the symbol may be unbound at this use, if it may be.
+    pub(crate) fn use_unbound_reason(&self, use_id: ScopedUseId) -> Option<UnboundReason> {
+        self.bindings_by_use[use_id].unbound_reason()
+    }
+
     pub(crate) fn public_bindings(
         &self,
         symbol: ScopedSymbolId,
@@ -298,6 +355,11 @@ impl<'db> UseDefMap<'db> {
         }
     }
 
+    /// Why the symbol may be unbound at the end of the scope, if it may be.
+    pub(crate) fn public_unbound_reason(&self, symbol: ScopedSymbolId) -> Option<UnboundReason> {
+        self.public_symbols[symbol].bindings().unbound_reason()
+    }
+
     pub(crate) fn bindings_at_declaration(
         &self,
         declaration: Definition<'db>,
@@ -543,8 +605,9 @@ impl<'db> UseDefMapBuilder<'db> {
 
     /// Merge the given snapshot into the current state, reflecting that we might have taken either
     /// path to get here. The new state for each symbol should include definitions from both the
-    /// prior state and the snapshot.
-    pub(super) fn merge(&mut self, snapshot: FlowSnapshot) {
+    /// prior state and the snapshot. `reason` describes the kind of control flow construct whose
+    /// paths are being joined, for attributing possible unboundness.
+    pub(super) fn merge(&mut self, snapshot: FlowSnapshot, reason: UnboundReason) {
         // We never remove symbols from `symbol_states` (it's an IndexVec, and the symbol
         // IDs must line up), so the current number of known symbols must always be equal to or
         // greater than the number of known symbols in a previously-taken snapshot.
@@ -553,10 +616,10 @@ impl<'db> UseDefMapBuilder<'db> {
         let mut snapshot_definitions_iter = snapshot.symbol_states.into_iter();
         for current in &mut self.symbol_states {
             if let Some(snapshot) = snapshot_definitions_iter.next() {
-                current.merge(snapshot);
+                current.merge(snapshot, reason);
             } else {
                 // Symbol not present in snapshot, so it's unbound/undeclared from that path.
-                current.set_may_be_unbound();
+                current.set_may_be_unbound(reason);
                 current.set_may_be_undeclared();
             }
         }
@@ -44,6 +44,7 @@
 //! Tracking live declarations is simpler, since constraints are not involved, but otherwise very
 //! similar to tracking live bindings.
 use super::bitset::{BitSet, BitSetIterator};
+use super::UnboundReason;
 use ruff_index::newtype_index;
 use smallvec::SmallVec;
 
@@ -138,8 +139,8 @@ pub(super) struct SymbolBindings {
     /// binding in `live_bindings`.
     constraints: Constraints,
 
-    /// Could the symbol be unbound at this point?
-    may_be_unbound: bool,
+    /// Could the symbol be unbound at this point? If so, why?
+    unbound_reason: Option<UnboundReason>,
 }
 
 impl SymbolBindings {
@@ -147,13 +148,16 @@ impl SymbolBindings {
         Self {
             live_bindings: Bindings::default(),
             constraints: Constraints::default(),
-            may_be_unbound: true,
+            unbound_reason: Some(UnboundReason::ScopeStart),
         }
     }
 
-    /// Add Unbound as a possibility for this symbol.
-    fn set_may_be_unbound(&mut self) {
-        self.may_be_unbound = true;
+    /// Add Unbound (attributed to `reason`) as a possibility for this symbol.
+    fn set_may_be_unbound(&mut self, reason: UnboundReason) {
+        // Keep a reason attributed by an inner construct, if there already is one.
+        if self.unbound_reason.is_none() {
+            self.unbound_reason = Some(reason);
+        }
     }
 
     /// Record a newly-encountered binding for this symbol.
@@ -163,7 +167,7 @@ impl SymbolBindings {
         self.live_bindings = Bindings::with(binding_id.into());
         self.constraints = Constraints::with_capacity(1);
         self.constraints.push(BitSet::default());
-        self.may_be_unbound = false;
+        self.unbound_reason = None;
     }
 
     /// Add given constraint to all live bindings.
@@ -182,7 +186,11 @@ impl SymbolBindings {
     }
 
     pub(super) fn may_be_unbound(&self) -> bool {
-        self.may_be_unbound
+        self.unbound_reason.is_some()
+    }
+
+    pub(super) fn unbound_reason(&self) -> Option<UnboundReason> {
+        self.unbound_reason
     }
 }
 
@@ -201,9 +209,9 @@ impl SymbolState {
         }
     }
 
-    /// Add Unbound as a possibility for this symbol.
-    pub(super) fn set_may_be_unbound(&mut self) {
-        self.bindings.set_may_be_unbound();
+    /// Add Unbound (attributed to `reason`) as a possibility for this symbol.
+    pub(super) fn set_may_be_unbound(&mut self, reason: UnboundReason) {
+        self.bindings.set_may_be_unbound(reason);
     }
 
     /// Record a newly-encountered binding for this symbol.
@@ -226,13 +234,18 @@ impl SymbolState {
         self.declarations.record_declaration(declaration_id);
     }
 
-    /// Merge another [`SymbolState`] into this one.
-    pub(super) fn merge(&mut self, b: SymbolState) {
+    /// Merge another [`SymbolState`] into this one. `join_reason` describes the kind of control
+    /// flow construct whose paths are being joined, for attributing possible unboundness.
+    pub(super) fn merge(&mut self, b: SymbolState, join_reason: UnboundReason) {
         let mut a = Self {
             bindings: SymbolBindings {
                 live_bindings: Bindings::default(),
                 constraints: Constraints::default(),
-                may_be_unbound: self.bindings.may_be_unbound || b.bindings.may_be_unbound,
+                unbound_reason: UnboundReason::merge(
+                    self.bindings.unbound_reason,
+                    b.bindings.unbound_reason,
+                    join_reason,
+                ),
             },
             declarations: SymbolDeclarations {
                 live_declarations: self.declarations.live_declarations.clone(),
@@ -413,10 +426,14 @@ impl std::iter::FusedIterator for DeclarationIdIterator<'_> {}
 
 #[cfg(test)]
 mod tests {
-    use super::{ScopedConstraintId, ScopedDefinitionId, SymbolState};
+    use super::{ScopedConstraintId, ScopedDefinitionId, SymbolState, UnboundReason};
 
-    fn assert_bindings(symbol: &SymbolState, may_be_unbound: bool, expected: &[&str]) {
-        assert_eq!(symbol.may_be_unbound(), may_be_unbound);
+    fn assert_bindings(
+        symbol: &SymbolState,
+        unbound_reason: Option<UnboundReason>,
+        expected: &[&str],
+    ) {
+        assert_eq!(symbol.bindings().unbound_reason(), unbound_reason);
         let actual = symbol
             .bindings()
             .iter()
@@ -454,7 +471,7 @@ mod tests {
     fn unbound() {
         let sym = SymbolState::undefined();
 
-        assert_bindings(&sym, true, &[]);
+        assert_bindings(&sym, Some(UnboundReason::ScopeStart), &[]);
     }
 
     #[test]
@@ -462,16 +479,16 @@ mod tests {
         let mut sym = SymbolState::undefined();
         sym.record_binding(ScopedDefinitionId::from_u32(0));
 
-        assert_bindings(&sym, false, &["0<>"]);
+        assert_bindings(&sym, None, &["0<>"]);
     }
 
     #[test]
     fn set_may_be_unbound() {
         let mut sym = SymbolState::undefined();
         sym.record_binding(ScopedDefinitionId::from_u32(0));
-        sym.set_may_be_unbound();
+        sym.set_may_be_unbound(UnboundReason::Conditional);
 
-        assert_bindings(&sym, true, &["0<>"]);
+        assert_bindings(&sym, Some(UnboundReason::Conditional), &["0<>"]);
     }
 
     #[test]
@@ -480,7 +497,7 @@ mod tests {
         sym.record_binding(ScopedDefinitionId::from_u32(0));
         sym.record_constraint(ScopedConstraintId::from_u32(0));
 
-        assert_bindings(&sym, false, &["0<0>"]);
+        assert_bindings(&sym, None, &["0<0>"]);
     }
 
     #[test]
@@ -494,9 +511,9 @@ mod tests {
         sym0b.record_binding(ScopedDefinitionId::from_u32(0));
         sym0b.record_constraint(ScopedConstraintId::from_u32(0));
 
-        sym0a.merge(sym0b);
+        sym0a.merge(sym0b, UnboundReason::Conditional);
         let mut sym0 = sym0a;
-        assert_bindings(&sym0, false, &["0<0>"]);
+        assert_bindings(&sym0, None, &["0<0>"]);
 
         // merging the same definition with differing constraints drops all constraints
         let mut sym1a = SymbolState::undefined();
@@ -507,25 +524,27 @@ mod tests {
         sym1b.record_binding(ScopedDefinitionId::from_u32(1));
         sym1b.record_constraint(ScopedConstraintId::from_u32(2));
 
-        sym1a.merge(sym1b);
+        sym1a.merge(sym1b, UnboundReason::Conditional);
         let sym1 = sym1a;
-        assert_bindings(&sym1, false, &["1<>"]);
+        assert_bindings(&sym1, None, &["1<>"]);
 
-        // merging a constrained definition with unbound keeps both
+        // merging a constrained definition with unbound keeps both, and attributes the
+        // unbound possibility to the joining construct
         let mut sym2a = SymbolState::undefined();
         sym2a.record_binding(ScopedDefinitionId::from_u32(2));
         sym2a.record_constraint(ScopedConstraintId::from_u32(3));
 
         let sym2b = SymbolState::undefined();
 
-        sym2a.merge(sym2b);
+        sym2a.merge(sym2b, UnboundReason::Try);
         let sym2 = sym2a;
-        assert_bindings(&sym2, true, &["2<3>"]);
+        assert_bindings(&sym2, Some(UnboundReason::Try), &["2<3>"]);
 
-        // merging different definitions keeps them each with their existing constraints
-        sym0.merge(sym2);
+        // merging different definitions keeps them each with their existing constraints,
+        // and keeps the reason attributed by the inner merge
+        sym0.merge(sym2, UnboundReason::Conditional);
         let sym = sym0;
-        assert_bindings(&sym, true, &["0<0>", "2<3>"]);
+        assert_bindings(&sym, Some(UnboundReason::Try), &["0<0>", "2<3>"]);
     }
 
     #[test]
@@ -560,7 +579,7 @@ mod tests {
         let mut sym2 = SymbolState::undefined();
         sym2.record_declaration(ScopedDefinitionId::from_u32(2));
 
-        sym.merge(sym2);
+        sym.merge(sym2, UnboundReason::Conditional);
 
         assert_declarations(&sym, false, &[1, 2]);
     }
@@ -572,7 +591,7 @@ mod tests {
 
         let sym2 = SymbolState::undefined();
 
-        sym.merge(sym2);
+        sym.merge(sym2, UnboundReason::Conditional);
 
         assert_declarations(&sym, true, &[1]);
     }
@@ -181,6 +181,7 @@ mod tests {
             &db,
             &ProgramSettings {
                 target_version: PythonVersion::default(),
+                respect_noqa: false,
                 search_paths: SearchPathSettings::new(SystemPathBuf::from("/src")),
             },
         )?;
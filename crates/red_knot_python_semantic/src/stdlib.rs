@@ -15,6 +15,7 @@ pub(crate) enum CoreStdlibModule {
     TypingExtensions,
     Typing,
     Sys,
+    Enum,
 }
 
 impl CoreStdlibModule {
@@ -26,6 +27,7 @@ impl CoreStdlibModule {
             Self::Typeshed => "_typeshed",
             Self::TypingExtensions => "typing_extensions",
             Self::Sys => "sys",
+            Self::Enum => "enum",
         }
     }
 
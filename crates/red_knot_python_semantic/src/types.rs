@@ -2,21 +2,27 @@ use std::hash::Hash;
 
 use indexmap::IndexSet;
 use itertools::Itertools;
+use rustc_hash::FxHashSet;
 
 use ruff_db::files::File;
+use ruff_db::parsed::parsed_module;
 use ruff_python_ast as ast;
+use ruff_text_size::{Ranged, TextRange};
 
 pub(crate) use self::builder::{IntersectionBuilder, UnionBuilder};
 pub use self::diagnostic::{TypeCheckDiagnostic, TypeCheckDiagnostics};
 pub(crate) use self::display::TypeArrayDisplay;
 pub(crate) use self::infer::{
-    infer_deferred_types, infer_definition_types, infer_expression_types, infer_scope_types,
+    infer_deferred_types, infer_definition_types, infer_expression_types, infer_generator_yield_ty,
+    infer_scope_types,
 };
 pub(crate) use self::signatures::Signature;
 use crate::module_resolver::file_to_module;
 use crate::semantic_index::ast_ids::HasScopedAstId;
 use crate::semantic_index::definition::Definition;
-use crate::semantic_index::symbol::{self as symbol, ScopeId, ScopedSymbolId};
+use crate::semantic_index::symbol::{
+    self as symbol, NodeWithScopeKind, ScopeId, ScopeKind, ScopedSymbolId,
+};
 use crate::semantic_index::{
     global_scope, semantic_index, symbol_table, use_def_map, BindingWithConstraints,
     BindingWithConstraintsIterator, DeclarationsIterator,
@@ -25,7 +31,7 @@ use crate::stdlib::{
     builtins_symbol, core_module_symbol, typing_extensions_symbol, CoreStdlibModule,
 };
 use crate::symbol::{Boundness, Symbol};
-use crate::types::diagnostic::TypeCheckDiagnosticsBuilder;
+use crate::types::diagnostic::{Rule, TypeCheckDiagnosticsBuilder};
 use crate::types::mro::{ClassBase, Mro, MroError, MroIterator};
 use crate::types::narrow::narrowing_constraint;
 use crate::{Db, FxOrderSet, Module, Program};
@@ -38,8 +44,19 @@ mod mro;
 mod narrow;
 mod signatures;
 mod string_annotation;
+mod suppression;
 mod unpacker;
 
+/// Maximum structural size (see [`Type::size`]) of an inferred type before diagnostics
+/// summarize it instead of printing it in full, and a `type-too-complex` diagnostic is
+/// emitted at the binding that created it.
+///
+/// Literal widening usually keeps inferred types well below this limit; it is only likely
+/// to be reached by generated or macro-heavy code.
+///
+/// TODO: make this configurable through the program settings.
+pub(crate) const TYPE_COMPLEXITY_LIMIT: usize = 256;
+
 #[salsa::tracked(return_ref)]
 pub fn check_types(db: &dyn Db, file: File) -> TypeCheckDiagnostics {
     let _span = tracing::trace_span!("check_types", file=?file.path(db)).entered();
@@ -54,6 +71,8 @@ pub fn check_types(db: &dyn Db, file: File) -> TypeCheckDiagnostics {
         diagnostics.extend(result.diagnostics());
     }
 
+    suppression::check_suppressions(db, file, &mut diagnostics);
+
     diagnostics
 }
 
@@ -63,10 +82,25 @@ fn symbol_by_id<'db>(db: &'db dyn Db, scope: ScopeId<'db>, symbol: ScopedSymbolI
 
     let use_def = use_def_map(db, scope);
 
+    // Stub files use "last declaration wins" semantics rather than flow-sensitive union
+    // semantics: alternative definitions of a symbol (under `sys.platform` guards, or
+    // version guards the semantic index couldn't evaluate statically) describe the same
+    // symbol, so the textually last live definition is the visible one, and a symbol
+    // defined in only some branches is still exported rather than possibly-unbound.
+    let is_stub = scope.file(db).is_stub(db.upcast());
+
     // If the symbol is declared, the public type is based on declarations; otherwise, it's based
     // on inference from bindings.
     if use_def.has_public_declarations(symbol) {
         let declarations = use_def.public_declarations(symbol);
+
+        if is_stub {
+            let last = declarations
+                .last()
+                .expect("must have at least one declaration if has_public_declarations is true");
+            return Symbol::Type(declaration_ty(db, last), Boundness::Bound);
+        }
+
         // If the symbol is undeclared in some paths, include the inferred type in the public type.
         let undeclared_ty = if declarations.may_be_undeclared() {
             Some(
@@ -105,6 +139,14 @@ fn symbol_by_id<'db>(db: &'db dyn Db, scope: ScopeId<'db>, symbol: ScopedSymbolI
                 Boundness::Bound,
             ),
         }
+    } else if is_stub {
+        use_def
+            .public_bindings(symbol)
+            .last()
+            .map(|BindingWithConstraints { binding, .. }| {
+                Symbol::Type(binding_ty(db, binding), Boundness::Bound)
+            })
+            .unwrap_or(Symbol::Unbound)
     } else {
         bindings_ty(db, use_def.public_bindings(symbol))
             .map(|bindings_ty| Symbol::Type(bindings_ty, use_def.public_boundness(symbol)))
@@ -156,6 +198,87 @@ fn module_type_symbols<'db>(db: &'db dyn Db) -> smallvec::SmallVec<[ast::name::N
         .collect()
 }
 
+/// The names listed in a module's top-level `__all__ = [...]` (or tuple) assignment, or
+/// `None` if the module doesn't define `__all__` syntactically. Non-string elements are
+/// ignored here; they are reported with `invalid-all` diagnostics during type checking.
+#[salsa::tracked(return_ref)]
+pub(crate) fn dunder_all_names(db: &dyn Db, file: File) -> Option<FxHashSet<ast::name::Name>> {
+    let parsed = parsed_module(db.upcast(), file);
+    let mut names: Option<FxHashSet<ast::name::Name>> = None;
+    for stmt in &parsed.syntax().body {
+        let ast::Stmt::Assign(assign) = stmt else {
+            continue;
+        };
+        if !assign
+            .targets
+            .iter()
+            .any(|target| matches!(target, ast::Expr::Name(name) if name.id == "__all__"))
+        {
+            continue;
+        }
+        let elements = match &*assign.value {
+            ast::Expr::List(list) => &list.elts,
+            ast::Expr::Tuple(tuple) => &tuple.elts,
+            _ => continue,
+        };
+        let names = names.get_or_insert_with(FxHashSet::default);
+        for element in elements {
+            if let ast::Expr::StringLiteral(literal) = element {
+                names.insert(ast::name::Name::new(literal.value.to_str()));
+            }
+        }
+    }
+    names
+}
+
+/// Resolve `name` through the `from m import *` statements in `file`'s global scope, if any.
+///
+/// A star import binds the imported module's exported names in the global scope, but the
+/// names themselves don't appear in the file's symbol table: the symbol table records a
+/// single `*` symbol, bound to the star-imported module(s). A module exports a name if it's
+/// listed in the module's `__all__`, or, when the module defines no `__all__`, if the name
+/// doesn't start with an underscore.
+pub(crate) fn star_import_symbol<'db>(db: &'db dyn Db, file: File, name: &str) -> Symbol<'db> {
+    if name == "*" {
+        return Symbol::Unbound;
+    }
+    let Symbol::Type(star_ty, _) = symbol(db, global_scope(db, file), "*") else {
+        return Symbol::Unbound;
+    };
+    let mut modules = vec![];
+    match star_ty {
+        Type::ModuleLiteral(module_file) => modules.push(module_file),
+        Type::Union(union) => {
+            for ty in union.elements(db) {
+                if let Type::ModuleLiteral(module_file) = ty {
+                    modules.push(*module_file);
+                }
+            }
+        }
+        _ => {}
+    }
+    for module_file in modules {
+        if module_file == file {
+            continue;
+        }
+        let exported = match dunder_all_names(db, module_file) {
+            Some(all_names) => all_names.contains(name),
+            None => !name.starts_with('_'),
+        };
+        if !exported {
+            continue;
+        }
+        // Look the name up directly in the module's global scope rather than through
+        // `global_symbol`, so that cycles of star imports can't recurse endlessly;
+        // names re-exported through a *chain* of star imports aren't resolved yet.
+        let module_symbol = symbol(db, global_scope(db, module_file), name);
+        if !module_symbol.is_unbound() {
+            return module_symbol;
+        }
+    }
+    Symbol::Unbound
+}
+
 /// Looks up a module-global symbol by name in a file.
 pub(crate) fn global_symbol<'db>(db: &'db dyn Db, file: File, name: &str) -> Symbol<'db> {
     let explicit_symbol = symbol(db, global_scope(db, file), name);
@@ -164,6 +287,13 @@ pub(crate) fn global_symbol<'db>(db: &'db dyn Db, file: File, name: &str) -> Sym
         return explicit_symbol;
     }
 
+    // Names bound by a `from m import *` statement don't appear in the symbol table
+    // individually; resolve them through the star-imported module's exports.
+    let star_symbol = star_import_symbol(db, file, name);
+    if !star_symbol.is_unbound() {
+        return explicit_symbol.or_fall_back_to(db, &star_symbol);
+    }
+
     // Not defined explicitly in the global scope?
     // All modules are instances of `types.ModuleType`;
     // look it up there (with a few very special exceptions)
@@ -171,9 +301,7 @@ pub(crate) fn global_symbol<'db>(db: &'db dyn Db, file: File, name: &str) -> Sym
         .iter()
         .any(|module_type_member| &**module_type_member == name)
     {
-        // TODO: this should use `.to_instance(db)`. but we don't understand attribute access
-        // on instance types yet.
-        let module_type_member = KnownClass::ModuleType.to_class_literal(db).member(db, name);
+        let module_type_member = KnownClass::ModuleType.to_instance(db).member(db, name);
         return explicit_symbol.or_fall_back_to(db, &module_type_member);
     }
 
@@ -353,6 +481,25 @@ pub enum Type<'db> {
     SubclassOf(SubclassOfType<'db>),
     /// The set of Python objects with the given class in their __class__'s method resolution order
     Instance(InstanceType<'db>),
+    /// An instance of a generic class for which we know the type arguments it was
+    /// specialized with, e.g. `list[int]` or `dict[str, int]`
+    GenericInstance(GenericInstanceType<'db>),
+    /// An instance of an iterator class for which we know the type of the values
+    /// yielded by each iteration step (e.g. the object returned by a call to `zip()`)
+    Iterator(IteratorType<'db>),
+    /// An instance of a coroutine object (the result of calling an `async` function)
+    /// for which we know the type of the value produced by awaiting it
+    Coroutine(CoroutineType<'db>),
+    /// An instance of `builtins.super` created by a call to `super()` or `super(C, obj)`,
+    /// which proxies attribute access to the classes following `C` in the MRO
+    BoundSuper(BoundSuperType<'db>),
+    /// The set of all callable objects accepting the given parameter types (positionally)
+    /// and returning the given type, as spelled with `Callable[[int], str]`
+    Callable(CallableType<'db>),
+    /// The declared return type of a type-guard function, as spelled with `TypeGuard[str]`
+    /// or `TypeIs[str]`: a `bool` that narrows the guarded argument at call sites used as
+    /// branch conditions
+    TypeGuard(TypeGuardType<'db>),
     /// A single Python object that requires special treatment in the type system
     KnownInstance(KnownInstanceType<'db>),
     /// The set of objects in any of the types in the union
@@ -375,7 +522,7 @@ pub enum Type<'db> {
     /// A heterogeneous tuple type, with elements of the given types in source order.
     // TODO: Support variable length homogeneous tuple type like `tuple[int, ...]`.
     Tuple(TupleType<'db>),
-    // TODO protocols, callable types, overloads, generics, type vars
+    // TODO overloads, generics, type vars
 }
 
 impl<'db> Type<'db> {
@@ -652,7 +799,85 @@ impl<'db> Type<'db> {
             (left, Type::KnownInstance(right)) => {
                 left.is_subtype_of(db, right.instance_fallback(db))
             }
-            (Type::Instance(left), Type::Instance(right)) => left.is_instance_of(db, right.class),
+            (Type::GenericInstance(left), right) => {
+                Type::instance(left.class(db)).is_subtype_of(db, right)
+            }
+            (left, Type::GenericInstance(right)) => {
+                left.is_subtype_of(db, Type::instance(right.class(db)))
+            }
+            (Type::Iterator(left), right) => {
+                Type::instance(left.class(db)).is_subtype_of(db, right)
+            }
+            (left, Type::Iterator(right)) => {
+                left.is_subtype_of(db, Type::instance(right.class(db)))
+            }
+            (Type::Coroutine(left), right) => {
+                Type::instance(left.class(db)).is_subtype_of(db, right)
+            }
+            (left, Type::Coroutine(right)) => {
+                left.is_subtype_of(db, Type::instance(right.class(db)))
+            }
+            (Type::BoundSuper(_), right) => {
+                KnownClass::Super.to_instance(db).is_subtype_of(db, right)
+            }
+            (left, Type::BoundSuper(_)) => {
+                left.is_subtype_of(db, KnownClass::Super.to_instance(db))
+            }
+            (Type::Callable(self_callable), Type::Callable(target_callable)) => {
+                // Parameters are contravariant (the supertype may accept more general
+                // arguments), the return type is covariant. A gradual parameter list
+                // (`Callable[..., T]`) is not fully static, so it never takes part in
+                // subtyping; `is_assignable_to` handles it instead.
+                let (Some(self_parameters), Some(target_parameters)) = (
+                    self_callable.parameter_types(db).as_deref(),
+                    target_callable.parameter_types(db).as_deref(),
+                ) else {
+                    return false;
+                };
+                self_parameters.len() == target_parameters.len()
+                    && target_parameters.iter().zip(self_parameters).all(
+                        |(target_parameter, self_parameter)| {
+                            target_parameter.is_subtype_of(db, *self_parameter)
+                        },
+                    )
+                    && self_callable
+                        .return_ty(db)
+                        .is_subtype_of(db, target_callable.return_ty(db))
+            }
+            (Type::FunctionLiteral(self_function), Type::Callable(target_callable)) => {
+                // TODO: take default values, variadic parameters and keyword-only
+                // parameters into account
+                let signature = self_function.signature(db);
+                let self_parameters = signature.positional_parameter_types();
+                let Some(target_parameters) = target_callable.parameter_types(db).as_deref() else {
+                    // A gradual parameter list is not fully static; see above.
+                    return false;
+                };
+                self_parameters.len() == target_parameters.len()
+                    && target_parameters.iter().zip(self_parameters).all(
+                        |(target_parameter, self_parameter)| {
+                            target_parameter.is_subtype_of(db, self_parameter)
+                        },
+                    )
+                    && signature
+                        .return_ty
+                        .is_subtype_of(db, target_callable.return_ty(db))
+            }
+            // At runtime a type-guard function simply returns a `bool`; the guarded type only
+            // matters for narrowing at call sites.
+            (Type::TypeGuard(_), right) => {
+                KnownClass::Bool.to_instance(db).is_subtype_of(db, right)
+            }
+            (left, Type::TypeGuard(_)) => {
+                left.is_subtype_of(db, KnownClass::Bool.to_instance(db))
+            }
+            (Type::Instance(left), Type::Instance(right)) => {
+                left.is_instance_of(db, right.class)
+                    // A nominally-unrelated class can still satisfy a protocol class
+                    // structurally, member by member.
+                    || (right.class.is_protocol(db)
+                        && right.class.is_protocol_satisfied_by(db, left.class))
+            }
             // TODO
             _ => false,
         }
@@ -686,6 +911,91 @@ impl<'db> Type<'db> {
                         },
                     )
             }
+            (Type::Callable(self_callable), Type::Callable(target_callable)) => {
+                // Same contravariant-parameter/covariant-return relation as in
+                // `is_subtype_of`, but using assignability so that gradual forms in
+                // parameter or return position are tolerated.
+                let parameters_assignable = match (
+                    self_callable.parameter_types(db).as_deref(),
+                    target_callable.parameter_types(db).as_deref(),
+                ) {
+                    // A gradual parameter list (`Callable[..., T]`) is consistent
+                    // with any parameter list, in either direction.
+                    (None, _) | (_, None) => true,
+                    (Some(self_parameters), Some(target_parameters)) => {
+                        self_parameters.len() == target_parameters.len()
+                            && target_parameters.iter().zip(self_parameters).all(
+                                |(target_parameter, self_parameter)| {
+                                    target_parameter.is_assignable_to(db, *self_parameter)
+                                },
+                            )
+                    }
+                };
+                parameters_assignable
+                    && self_callable
+                        .return_ty(db)
+                        .is_assignable_to(db, target_callable.return_ty(db))
+            }
+            (Type::FunctionLiteral(self_function), Type::Callable(target_callable)) => {
+                // An unannotated parameter or return type (`Unknown`) shouldn't prevent a
+                // function from being assignable to a compatible `Callable`.
+                let signature = self_function.signature(db);
+                let parameters_assignable = match target_callable.parameter_types(db).as_deref() {
+                    // Every function is consistent with a gradual parameter list.
+                    None => true,
+                    Some(target_parameters) => {
+                        let self_parameters = signature.positional_parameter_types();
+                        self_parameters.len() == target_parameters.len()
+                            && target_parameters.iter().zip(self_parameters).all(
+                                |(target_parameter, self_parameter)| {
+                                    target_parameter.is_assignable_to(db, self_parameter)
+                                },
+                            )
+                    }
+                };
+                parameters_assignable
+                    && signature
+                        .return_ty
+                        .is_assignable_to(db, target_callable.return_ty(db))
+            }
+            (Type::ClassLiteral(ClassLiteralType { class }), Type::Callable(target_callable)) => {
+                // Calling a class constructs an instance of it, so a class is accepted
+                // where a compatible callable is expected; the parameters are those of
+                // `__init__`, with `self` already bound.
+                //
+                // TODO: take a custom metaclass `__call__`, a `__new__` with a diverging
+                // return type, and dataclass-generated constructors into account, as
+                // `Type::call` does.
+                let parameters_assignable = match target_callable.parameter_types(db).as_deref() {
+                    None => true,
+                    Some(target_parameters) => match class.init_method(db) {
+                        ClassInit::Method(init) => {
+                            let init_parameters = init.signature(db).positional_parameter_types();
+                            let self_parameters = init_parameters.get(1..).unwrap_or_default();
+                            self_parameters.len() == target_parameters.len()
+                                && target_parameters.iter().zip(self_parameters).all(
+                                    |(target_parameter, self_parameter)| {
+                                        target_parameter.is_assignable_to(db, *self_parameter)
+                                    },
+                                )
+                        }
+                        // `object.__init__` takes no arguments (beyond `self`).
+                        ClassInit::Object => target_parameters.is_empty(),
+                        // The constructor can't be resolved; don't complain about a
+                        // relation we can't verify.
+                        ClassInit::Unknown => true,
+                    },
+                };
+                parameters_assignable
+                    && Type::instance(class).is_assignable_to(db, target_callable.return_ty(db))
+            }
+            (Type::FunctionLiteral(self_function), Type::Instance(InstanceType { class }))
+                if class.is_protocol(db) =>
+            {
+                // A callback protocol — a protocol whose only member is `__call__` —
+                // accepts any function with a compatible signature.
+                class.callback_protocol_accepts(db, self_function)
+            }
             // TODO other types containing gradual forms (e.g. generics containing Any/Unknown)
             _ => self.is_subtype_of(db, target),
         }
@@ -809,6 +1119,39 @@ impl<'db> Type<'db> {
             (left, Type::KnownInstance(right)) => {
                 left.is_disjoint_from(db, right.instance_fallback(db))
             }
+            (Type::GenericInstance(left), right) => {
+                Type::instance(left.class(db)).is_disjoint_from(db, right)
+            }
+            (left, Type::GenericInstance(right)) => {
+                left.is_disjoint_from(db, Type::instance(right.class(db)))
+            }
+            (Type::Iterator(left), right) => {
+                Type::instance(left.class(db)).is_disjoint_from(db, right)
+            }
+            (left, Type::Iterator(right)) => {
+                left.is_disjoint_from(db, Type::instance(right.class(db)))
+            }
+            (Type::Coroutine(left), right) => {
+                Type::instance(left.class(db)).is_disjoint_from(db, right)
+            }
+            (left, Type::Coroutine(right)) => {
+                left.is_disjoint_from(db, Type::instance(right.class(db)))
+            }
+            (Type::BoundSuper(_), right) => {
+                KnownClass::Super.to_instance(db).is_disjoint_from(db, right)
+            }
+            (left, Type::BoundSuper(_)) => {
+                left.is_disjoint_from(db, KnownClass::Super.to_instance(db))
+            }
+            (Type::Callable(_), _) | (_, Type::Callable(_)) => {
+                // TODO: a callable type is disjoint from types whose instances can never
+                // have a `__call__` method, but we can't determine that for most types yet.
+                false
+            }
+            (Type::TypeGuard(_), other) | (other, Type::TypeGuard(_)) => {
+                // Type-guard return values are `bool`s at runtime.
+                other.is_disjoint_from(db, KnownClass::Bool.to_instance(db))
+            }
             (
                 Type::Instance(InstanceType { class: class_none }),
                 Type::Instance(InstanceType { class: class_other }),
@@ -955,6 +1298,18 @@ impl<'db> Type<'db> {
             Type::Instance(InstanceType { class }) => {
                 class.known(db).is_some_and(KnownClass::is_singleton)
             }
+            Type::GenericInstance(generic) => Type::instance(generic.class(db)).is_singleton(db),
+            Type::Iterator(iterator) => Type::instance(iterator.class(db)).is_singleton(db),
+            Type::Coroutine(coroutine) => Type::instance(coroutine.class(db)).is_singleton(db),
+            Type::BoundSuper(_) => false,
+            Type::Callable(..) => {
+                // Many distinct objects can share the same parameter and return types.
+                false
+            }
+            Type::TypeGuard(_) => {
+                // The set of `bool`s contains both `True` and `False`.
+                false
+            }
             Type::Tuple(..) => {
                 // The empty tuple is a singleton on CPython and PyPy, but not on other Python
                 // implementations such as GraalPy. Its *use* as a singleton is discouraged and
@@ -1007,30 +1362,66 @@ impl<'db> Type<'db> {
 
             Type::Instance(InstanceType { class }) => match class.known(db) {
                 Some(
-                    KnownClass::NoneType | KnownClass::NoDefaultType | KnownClass::VersionInfo,
+                    KnownClass::NoneType
+                    | KnownClass::EllipsisType
+                    | KnownClass::NotImplementedType
+                    | KnownClass::NoDefaultType
+                    | KnownClass::VersionInfo,
                 ) => true,
                 Some(
                     KnownClass::Bool
                     | KnownClass::Object
                     | KnownClass::Bytes
+                    | KnownClass::ByteArray
+                    | KnownClass::MemoryView
                     | KnownClass::Type
                     | KnownClass::Int
                     | KnownClass::Float
+                    | KnownClass::Complex
                     | KnownClass::Str
                     | KnownClass::List
                     | KnownClass::Tuple
                     | KnownClass::Set
+                    | KnownClass::FrozenSet
                     | KnownClass::Dict
                     | KnownClass::Slice
+                    | KnownClass::Range
+                    | KnownClass::Zip
+                    | KnownClass::Enumerate
+                    | KnownClass::Map
+                    | KnownClass::Filter
+                    | KnownClass::Classmethod
+                    | KnownClass::Staticmethod
+                    | KnownClass::Super
+                    | KnownClass::Property
+                    | KnownClass::Enum
+                    | KnownClass::IntEnum
+                    | KnownClass::StrEnum
+                    | KnownClass::Flag
+                    | KnownClass::IntFlag
                     | KnownClass::GenericAlias
                     | KnownClass::ModuleType
                     | KnownClass::FunctionType
+                    | KnownClass::GeneratorType
+                    | KnownClass::CoroutineType
                     | KnownClass::SpecialForm
-                    | KnownClass::TypeVar,
+                    | KnownClass::TypeVar
+                    | KnownClass::ParamSpec
+                    | KnownClass::Deprecated,
                 ) => false,
                 None => false,
             },
 
+            Type::GenericInstance(generic) => {
+                Type::instance(generic.class(db)).is_single_valued(db)
+            }
+
+            Type::Iterator(iterator) => Type::instance(iterator.class(db)).is_single_valued(db),
+
+            Type::Coroutine(coroutine) => Type::instance(coroutine.class(db)).is_single_valued(db),
+
+            Type::BoundSuper(_) | Type::Callable(_) | Type::TypeGuard(_) => false,
+
             Type::Any
             | Type::Never
             | Type::Unknown
@@ -1041,6 +1432,25 @@ impl<'db> Type<'db> {
         }
     }
 
+    /// Return the structural size of this type: the number of nodes in the type tree.
+    ///
+    /// Types whose printed form would be unusably large (e.g. unions of hundreds of tuples
+    /// of unions) can be detected by comparing this against [`TYPE_COMPLEXITY_LIMIT`]. The
+    /// sizes of the interned composite types are computed lazily and cached.
+    pub(crate) fn size(&self, db: &'db dyn Db) -> usize {
+        match self {
+            Type::Union(union) => union_size(db, *union),
+            Type::Intersection(intersection) => intersection_size(db, *intersection),
+            Type::Tuple(tuple) => tuple_size(db, *tuple),
+            Type::Callable(callable) => callable_size(db, *callable),
+            Type::TypeGuard(guard) => 1 + guard.guarded_ty(db).size(db),
+            Type::GenericInstance(generic) => generic_instance_size(db, *generic),
+            Type::Iterator(iterator) => 1 + iterator.yields(db).size(db),
+            Type::Coroutine(coroutine) => 1 + coroutine.result(db).size(db),
+            _ => 1,
+        }
+    }
+
     /// Resolve a member access of a type.
     ///
     /// For example, if `foo` is `Type::Instance(<Bar>)`,
@@ -1061,12 +1471,11 @@ impl<'db> Type<'db> {
             }
             Type::ModuleLiteral(file) => {
                 // `__dict__` is a very special member that is never overridden by module globals;
-                // we should always look it up directly as an attribute on `types.ModuleType`,
-                // never in the global scope of the module.
+                // it always resolves to the module dictionary, never to a global scope symbol.
+                // TODO: `types.ModuleType.__dict__` is declared as `dict[str, Any]`; use that
+                // once we can represent generic `dict` instances.
                 if name == "__dict__" {
-                    return KnownClass::ModuleType
-                        .to_instance(db)
-                        .member(db, "__dict__");
+                    return KnownClass::Dict.to_instance(db).into();
                 }
 
                 let global_lookup = symbol(db, global_scope(db, *file), name);
@@ -1087,9 +1496,8 @@ impl<'db> Type<'db> {
                 // to help out with dynamic imports; we shouldn't use it for `ModuleLiteral` types
                 // where we know exactly which module we're dealing with.
                 if name != "__getattr__" && global_lookup.possibly_unbound() {
-                    // TODO: this should use `.to_instance()`, but we don't understand instance attribute yet
                     let module_type_instance_member =
-                        KnownClass::ModuleType.to_class_literal(db).member(db, name);
+                        KnownClass::ModuleType.to_instance(db).member(db, name);
                     global_lookup.or_fall_back_to(db, &module_type_instance_member)
                 } else {
                     global_lookup
@@ -1106,11 +1514,73 @@ impl<'db> Type<'db> {
                     (Some(KnownClass::VersionInfo), "minor") => {
                         Type::IntLiteral(Program::get(db).target_version(db).minor.into())
                     }
-                    // TODO MRO? get_own_instance_member, get_instance_member
-                    _ => Type::Todo,
+                    _ => {
+                        // A classmethod accessed on an instance is looked up on the class, with
+                        // `cls` bound to the instance's class; a staticmethod is looked up on
+                        // the class and behaves like a plain function, with no implicit first
+                        // argument bound.
+                        if let Symbol::Type(Type::FunctionLiteral(function), boundness) =
+                            class.class_member(db, name)
+                        {
+                            if function.is_classmethod(db) || function.is_staticmethod(db) {
+                                return Symbol::Type(Type::FunctionLiteral(function), boundness);
+                            }
+                            // Reading a `property` on the instance invokes the getter, so the
+                            // member has the getter's return type.
+                            if function.is_property(db) {
+                                return Symbol::Type(
+                                    function.signature(db).return_ty,
+                                    boundness,
+                                );
+                            }
+                            // When the property also has a setter, the class-body symbol is
+                            // bound to the `.setter`-decorated function; the getter must be
+                            // looked up explicitly.
+                            if let Some(getter) = class.property_getter(db, name) {
+                                return Symbol::Type(getter.signature(db).return_ty, boundness);
+                            }
+                        }
+                        // The annotated fields of a dataclass are instance attributes with
+                        // their declared types.
+                        if class.is_dataclass(db)
+                            && class
+                                .dataclass_fields(db)
+                                .iter()
+                                .any(|field| field.name.as_str() == name)
+                        {
+                            if let Symbol::Type(field_ty, boundness) =
+                                class.own_class_member(db, name)
+                            {
+                                return Symbol::Type(field_ty, boundness);
+                            }
+                        }
+                        // Any other class attribute is accessible through the instance,
+                        // looked up along the MRO.
+                        if let Symbol::Type(ty, boundness) = class.class_member(db, name) {
+                            return Symbol::Type(ty, boundness);
+                        }
+                        // TODO: implicit instance attributes assigned in methods
+                        // (`self.x = ...`) aren't discovered yet, so the attribute
+                        // can only be reported as unresolved if no method could have
+                        // created one.
+                        if class.may_define_implicit_attributes(db) {
+                            Type::Todo
+                        } else {
+                            return Symbol::Unbound;
+                        }
+                    }
                 };
                 ty.into()
             }
+            Type::GenericInstance(generic) => Type::instance(generic.class(db)).member(db, name),
+            Type::Iterator(iterator) => Type::instance(iterator.class(db)).member(db, name),
+            Type::Coroutine(coroutine) => Type::instance(coroutine.class(db)).member(db, name),
+            Type::BoundSuper(bound_super) => bound_super.member(db, name),
+            Type::Callable(_) => {
+                // TODO: defer to the members of `builtins.object`, which every callable has
+                Type::Todo.into()
+            }
+            Type::TypeGuard(_) => KnownClass::Bool.to_instance(db).member(db, name),
             Type::Union(union) => {
                 let mut builder = UnionBuilder::new(db);
 
@@ -1191,10 +1661,43 @@ impl<'db> Type<'db> {
             Type::Any | Type::Todo | Type::Never | Type::Unknown => Truthiness::Ambiguous,
             Type::FunctionLiteral(_) => Truthiness::AlwaysTrue,
             Type::ModuleLiteral(_) => Truthiness::AlwaysTrue,
-            Type::ClassLiteral(_) => {
-                // TODO: lookup `__bool__` and `__len__` methods on the class's metaclass
-                // More info in https://docs.python.org/3/library/stdtypes.html#truth-value-testing
-                Truthiness::Ambiguous
+            Type::ClassLiteral(ClassLiteralType { class }) => {
+                // The truthiness of a class is governed by its metaclass:
+                // https://docs.python.org/3/library/stdtypes.html#truth-value-testing
+                let Some(ClassLiteralType { class: metaclass }) =
+                    class.metaclass(db).into_class_literal()
+                else {
+                    return Truthiness::Ambiguous;
+                };
+
+                match metaclass.class_member(db, "__bool__") {
+                    // Check for `__bool__ = bool` to avoid infinite recursion, since
+                    // `Type::call` on `bool` will call `Type::bool` on the argument.
+                    Symbol::Type(bool_method, Boundness::Bound)
+                        if !bool_method.into_class_literal().is_some_and(
+                            |ClassLiteralType { class }| class.is_known(db, KnownClass::Bool),
+                        ) =>
+                    {
+                        if let Some(Type::BooleanLiteral(bool_val)) = bool_method
+                            .call(db, &CallArguments::positional([*self]))
+                            .return_ty(db)
+                        {
+                            bool_val.into()
+                        } else {
+                            Truthiness::Ambiguous
+                        }
+                    }
+                    Symbol::Type(..) => Truthiness::Ambiguous,
+                    Symbol::Unbound => {
+                        // Neither `type` nor `object` defines `__bool__` or `__len__`, so
+                        // unless the metaclass provides one of them, a class is always truthy.
+                        if metaclass.class_member(db, "__len__").is_unbound() {
+                            Truthiness::AlwaysTrue
+                        } else {
+                            Truthiness::Ambiguous
+                        }
+                    }
+                }
             }
             Type::SubclassOf(_) => {
                 // TODO: see above
@@ -1227,8 +1730,9 @@ impl<'db> Type<'db> {
                         return Truthiness::Ambiguous;
                     }
 
-                    if let Some(Type::BooleanLiteral(bool_val)) =
-                        bool_method.call(db, &[*instance_ty]).return_ty(db)
+                    if let Some(Type::BooleanLiteral(bool_val)) = bool_method
+                        .call(db, &CallArguments::positional([*instance_ty]))
+                        .return_ty(db)
                     {
                         bool_val.into()
                     } else {
@@ -1237,6 +1741,18 @@ impl<'db> Type<'db> {
                 }
             }
             Type::KnownInstance(known_instance) => known_instance.bool(),
+            Type::GenericInstance(generic) => Type::instance(generic.class(db)).bool(db),
+            Type::Iterator(iterator) => Type::instance(iterator.class(db)).bool(db),
+            Type::Coroutine(coroutine) => Type::instance(coroutine.class(db)).bool(db),
+            Type::BoundSuper(_) => Truthiness::AlwaysTrue,
+            Type::Callable(_) => {
+                // The class of a callable object could define `__bool__`.
+                Truthiness::Ambiguous
+            }
+            Type::TypeGuard(_) => {
+                // A type-guard return value is a `bool`, either of whose values may occur.
+                Truthiness::Ambiguous
+            }
             Type::Union(union) => {
                 let union_elements = union.elements(db);
                 let first_element_truthiness = union_elements[0].bool(db);
@@ -1266,41 +1782,563 @@ impl<'db> Type<'db> {
         }
     }
 
+    /// Classify whether, and how confidently, objects of this type can be called.
+    ///
+    /// Unlike [`Type::call`], this doesn't need any arguments and never emits diagnostics,
+    /// so it can also be used by lint rules that reason about callables without modeling a
+    /// call (e.g. "callable passed where a value was expected").
+    pub fn callability(self, db: &'db dyn Db) -> Callability {
+        match self {
+            // These are callable, but nothing is known about the signature.
+            Type::Any | Type::Unknown | Type::Todo => Callability::UnknownSignature,
+
+            Type::FunctionLiteral(_)
+            | Type::ClassLiteral(_)
+            | Type::SubclassOf(_)
+            | Type::Callable(_) => Callability::Callable,
+
+            Type::Instance(InstanceType { class }) => match class.class_member(db, "__call__") {
+                Symbol::Type(dunder_call, Boundness::Bound) => {
+                    // `__call__ = None` is a common idiom for explicitly marking a class
+                    // as not callable.
+                    if matches!(
+                        dunder_call,
+                        Type::Instance(InstanceType { class })
+                            if class.is_known(db, KnownClass::NoneType)
+                    ) {
+                        Callability::DunderCallIsNone
+                    } else {
+                        dunder_call.callability(db)
+                    }
+                }
+                Symbol::Type(_, Boundness::PossiblyUnbound) => Callability::PossiblyNotCallable,
+                Symbol::Unbound => Callability::NotCallable,
+            },
+
+            Type::Union(union) => {
+                let mut any_callable = false;
+                let mut any_not_callable = false;
+                let mut unknown_signature = false;
+                let mut possibly_not_callable = false;
+                for element in union.elements(db) {
+                    match element.callability(db) {
+                        Callability::Callable => any_callable = true,
+                        Callability::UnknownSignature => {
+                            any_callable = true;
+                            unknown_signature = true;
+                        }
+                        Callability::PossiblyNotCallable => possibly_not_callable = true,
+                        Callability::NotCallable | Callability::DunderCallIsNone => {
+                            any_not_callable = true;
+                        }
+                    }
+                }
+                if possibly_not_callable || (any_callable && any_not_callable) {
+                    Callability::PossiblyNotCallable
+                } else if any_not_callable {
+                    Callability::NotCallable
+                } else if unknown_signature {
+                    Callability::UnknownSignature
+                } else {
+                    Callability::Callable
+                }
+            }
+
+            // TODO: an intersection is callable if any of its positive elements is.
+            Type::Intersection(_) => Callability::UnknownSignature,
+
+            Type::Never
+            | Type::ModuleLiteral(_)
+            | Type::GenericInstance(_)
+            | Type::Iterator(_)
+            | Type::Coroutine(_)
+            | Type::BoundSuper(_)
+            | Type::TypeGuard(_)
+            | Type::KnownInstance(_)
+            | Type::IntLiteral(_)
+            | Type::BooleanLiteral(_)
+            | Type::StringLiteral(_)
+            | Type::LiteralString
+            | Type::BytesLiteral(_)
+            | Type::SliceLiteral(_)
+            | Type::Tuple(_) => Callability::NotCallable,
+        }
+    }
+
     /// Return the outcome of calling an object of this type.
     #[must_use]
-    fn call(self, db: &'db dyn Db, arg_types: &[Type<'db>]) -> CallOutcome<'db> {
+    fn call(self, db: &'db dyn Db, arguments: &CallArguments<'db>) -> CallOutcome<'db> {
         match self {
-            // TODO validate typed call arguments vs callable signature
             Type::FunctionLiteral(function_type) => {
                 if function_type.is_known(db, KnownFunction::RevealType) {
                     CallOutcome::revealed(
                         function_type.signature(db).return_ty,
-                        *arg_types.first().unwrap_or(&Type::Unknown),
+                        arguments.first_positional().unwrap_or(Type::Unknown),
+                        arguments.count(),
                     )
+                } else if matches!(
+                    function_type.known(db),
+                    Some(KnownFunction::Vars | KnownFunction::GetTypeHints)
+                ) {
+                    // Both return a `dict[str, Any]`; we represent it as a bare `dict`
+                    // until we support generics.
+                    CallOutcome::callable(KnownClass::Dict.to_instance(db))
+                } else {
+                    // An overloaded function resolves to the first overload whose
+                    // parameters accept the arguments.
+                    if !function_type.overloads(db).is_empty() {
+                        // With keyword arguments or unpackings in the call we can't tell
+                        // which overload applies.
+                        if arguments.has_keywords() || arguments.has_variadic() {
+                            return CallOutcome::callable(Type::Todo);
+                        }
+                        return match function_type
+                            .matching_overload(db, arguments.positional_types())
+                        {
+                            Some(overload) => {
+                                CallOutcome::callable(overload.overload_signature(db).return_ty)
+                            }
+                            None => CallOutcome::NoMatchingOverload { callable_ty: self },
+                        };
+                    }
+                    // A `@functools.singledispatch` function dispatches on the type of its
+                    // first argument: select the most derived registered implementation the
+                    // argument is assignable to. Calls matching no registration fall
+                    // through to the base implementation's signature below.
+                    if function_type.is_singledispatch(db) {
+                        if let Some(argument_ty) = arguments.first_positional() {
+                            let mut best: Option<(Type<'db>, FunctionType<'db>)> = None;
+                            for (dispatch_ty, implementation) in
+                                function_type.singledispatch_registrations(db)
+                            {
+                                if !argument_ty.is_assignable_to(db, dispatch_ty) {
+                                    continue;
+                                }
+                                if best.map_or(true, |(best_ty, _)| {
+                                    dispatch_ty.is_subtype_of(db, best_ty)
+                                }) {
+                                    best = Some((dispatch_ty, implementation));
+                                }
+                            }
+                            if let Some((_, implementation)) = best {
+                                return CallOutcome::callable(
+                                    implementation.overload_signature(db).return_ty,
+                                );
+                            }
+                        }
+                    }
+                    let mut return_ty = function_type.signature(db).return_ty;
+                    // A bare typevar in return position is solved from the arguments bound
+                    // to parameters annotated with the same typevar; if no argument binds
+                    // it, fall back to the typevar's upper bound, or `Unknown`.
+                    // TODO: substitute typevars nested inside other types (e.g. `list[T]`)
+                    // once generic instances are supported.
+                    if let Type::KnownInstance(KnownInstanceType::TypeVar(typevar)) = return_ty {
+                        return_ty = arguments
+                            .solve_typevar(db, function_type.signature(db), typevar)
+                            .or_else(|| typevar.upper_bound(db))
+                            .unwrap_or(Type::Unknown);
+                    }
+                    // A `Callable[P, R]` return annotation referencing a `ParamSpec` is
+                    // solved from the argument bound to a `Callable[P, ...]` parameter;
+                    // this is the decorator pattern
+                    // `def decorator(f: Callable[P, R]) -> Callable[P, R]`.
+                    if let Type::Callable(return_callable) = return_ty {
+                        if let Some(param_spec) = return_callable.param_spec(db) {
+                            if let Some((parameter_callable, argument_ty)) = arguments
+                                .solve_param_spec(db, function_type.signature(db), param_spec)
+                            {
+                                if parameter_callable.return_ty(db) == return_callable.return_ty(db)
+                                {
+                                    // The parameter list and the return type both carry over
+                                    // unchanged, so the call preserves the argument's
+                                    // signature exactly.
+                                    return_ty = argument_ty;
+                                } else {
+                                    // Only the parameter list carries over; the return type
+                                    // comes from the return annotation.
+                                    let parameter_types = match argument_ty {
+                                        Type::Callable(callable) => {
+                                            callable.parameter_types(db).clone()
+                                        }
+                                        Type::FunctionLiteral(function) => Some(
+                                            function
+                                                .signature(db)
+                                                .positional_parameter_types()
+                                                .into_boxed_slice(),
+                                        ),
+                                        _ => None,
+                                    };
+                                    return_ty = Type::Callable(CallableType::new(
+                                        db,
+                                        parameter_types,
+                                        return_callable.return_ty(db),
+                                        None,
+                                    ));
+                                }
+                            }
+                        }
+                    }
+                    let mut errors = vec![];
+                    // Check the positional arguments against the function's parameter
+                    // list: first the count, then each argument type against the
+                    // annotated parameter type (an unannotated parameter accepts
+                    // anything). A `*args` unpacking provides an unknown number of
+                    // positional arguments, so both checks are skipped; keyword
+                    // arguments can fill the remaining positional-or-keyword
+                    // parameters, so only the lower bound is skipped.
+                    if let Some((required, maximum)) = function_type.positional_arity(db) {
+                        let positional_types = arguments.positional_types();
+                        if arguments.has_variadic() {
+                            // Not countable.
+                        } else if positional_types.len() > maximum {
+                            errors.push(CallArgumentError::TooManyArguments {
+                                expected: maximum,
+                                received: positional_types.len(),
+                            });
+                        } else if positional_types.len() < required && !arguments.has_keywords() {
+                            errors.push(CallArgumentError::TooFewArguments {
+                                expected: required,
+                                received: positional_types.len(),
+                            });
+                        } else {
+                            let parameter_types =
+                                function_type.signature(db).positional_parameter_types();
+                            for (argument_ty, parameter_ty) in
+                                positional_types.iter().zip(parameter_types.iter())
+                            {
+                                // TODO: a `TypeVar` parameter (generic function) can't
+                                // be checked yet.
+                                if matches!(parameter_ty, Type::KnownInstance(_)) {
+                                    continue;
+                                }
+                                if !argument_ty.is_assignable_to(db, *parameter_ty) {
+                                    errors.push(CallArgumentError::InvalidArgumentType {
+                                        parameter_ty: *parameter_ty,
+                                        argument_ty: *argument_ty,
+                                    });
+                                }
+                            }
+                        }
+                    }
+                    arguments.check_keywords(db, function_type.signature(db), &mut errors);
+                    if !errors.is_empty() {
+                        return CallOutcome::InvalidArguments {
+                            callable_ty: self,
+                            return_ty,
+                            errors: errors.into_boxed_slice(),
+                        };
+                    }
+                    CallOutcome::callable(return_ty)
+                }
+            }
+
+            Type::Callable(callable) => {
+                // The parameters of a `Callable` annotation are positional-only; a
+                // gradual parameter list (`Callable[..., T]`) accepts any arguments.
+                let mut errors = vec![];
+                if let Some(parameter_types) = callable.parameter_types(db).as_deref() {
+                    let positional_types = arguments.positional_types();
+                    if arguments.has_variadic() || arguments.has_keywords() {
+                        // We can't count the arguments provided by unpackings, and keyword
+                        // arguments may target parameters the `Callable` annotation erased
+                        // the names of.
+                    } else if parameter_types.len() != positional_types.len() {
+                        errors.push(CallArgumentError::WrongArity {
+                            expected: parameter_types.len(),
+                            received: positional_types.len(),
+                        });
+                    } else {
+                        for (parameter_ty, argument_ty) in
+                            parameter_types.iter().zip(positional_types)
+                        {
+                            if !argument_ty.is_assignable_to(db, *parameter_ty) {
+                                errors.push(CallArgumentError::InvalidArgumentType {
+                                    parameter_ty: *parameter_ty,
+                                    argument_ty: *argument_ty,
+                                });
+                            }
+                        }
+                    }
+                }
+                if errors.is_empty() {
+                    CallOutcome::callable(callable.return_ty(db))
                 } else {
-                    CallOutcome::callable(function_type.signature(db).return_ty)
+                    CallOutcome::InvalidArguments {
+                        callable_ty: self,
+                        return_ty: callable.return_ty(db),
+                        errors: errors.into_boxed_slice(),
+                    }
                 }
             }
 
-            // TODO annotated return type on `__new__` or metaclass `__call__`
             Type::ClassLiteral(ClassLiteralType { class }) => {
-                CallOutcome::callable(match class.known(db) {
+                // A call to `typing.TypeVar` creates a type variable, as in `T = TypeVar("T")`.
+                // The result is the `TypeVar` instance itself, so that annotations referring to
+                // `T` resolve to the type variable, the same as for a PEP 695 type parameter.
+                // TODO: extract constraints and the default from the call's arguments too.
+                if class.is_known(db, KnownClass::TypeVar) {
+                    if let Some(Type::StringLiteral(name)) = arguments.first_positional() {
+                        let bound_or_constraints = arguments.keyword_ty("bound").map(|bound| {
+                            TypeVarBoundOrConstraints::UpperBound(bound.in_type_expression(db))
+                        });
+                        return CallOutcome::callable(Type::KnownInstance(
+                            KnownInstanceType::TypeVar(TypeVarInstance::new(
+                                db,
+                                ast::name::Name::new(name.value(db)),
+                                bound_or_constraints,
+                                None,
+                            )),
+                        ));
+                    }
+                }
+                // Likewise, a call to `typing.ParamSpec` creates a parameter specification,
+                // as in `P = ParamSpec("P")`.
+                if class.is_known(db, KnownClass::ParamSpec) {
+                    if let Some(Type::StringLiteral(name)) = arguments.first_positional() {
+                        return CallOutcome::callable(Type::KnownInstance(
+                            KnownInstanceType::ParamSpec(ParamSpecInstance::new(
+                                db,
+                                ast::name::Name::new(name.value(db)),
+                                None,
+                            )),
+                        ));
+                    }
+                }
+                // A custom metaclass `__call__` takes over the construction protocol
+                // entirely: it receives the call arguments and its return value is the
+                // result of the call, whether or not `__new__`/`__init__` ever run. We
+                // use its annotated return type and skip the constructor checks below,
+                // which assume the default `type.__call__` behavior.
+                if let Some(dunder_call) = class.metaclass_dunder_call(db) {
+                    let function_stmt_node =
+                        dunder_call.body_scope(db).node(db).expect_function();
+                    let return_ty = if function_stmt_node.returns.is_some() {
+                        dunder_call.signature(db).return_ty
+                    } else {
+                        Type::instance(class)
+                    };
+                    return CallOutcome::callable(return_ty);
+                }
+                // A `@dataclass`-decorated class is constructed through its generated
+                // `__init__`, whose parameters are the annotated fields of the class body.
+                if class.is_dataclass(db) {
+                    let fields = class.dataclass_fields(db);
+                    let required = fields.iter().filter(|field| !field.has_default).count();
+                    let instance_ty = Type::instance(class);
+                    let positional_types = arguments.positional_types();
+                    let mut errors = vec![];
+                    if arguments.has_variadic() || arguments.has_keywords() {
+                        // With unpackings we can't count the arguments; keyword
+                        // arguments fill fields by name, so only the positional prefix
+                        // is checked below.
+                    } else if positional_types.len() < required
+                        || positional_types.len() > fields.len()
+                    {
+                        errors.push(CallArgumentError::WrongArity {
+                            expected: if positional_types.len() < required {
+                                required
+                            } else {
+                                fields.len()
+                            },
+                            received: positional_types.len(),
+                        });
+                    }
+                    if errors.is_empty() && !arguments.has_variadic() {
+                        for (argument_ty, field) in positional_types.iter().zip(&fields) {
+                            if !argument_ty.is_assignable_to(db, field.ty) {
+                                errors.push(CallArgumentError::InvalidArgumentType {
+                                    parameter_ty: field.ty,
+                                    argument_ty: *argument_ty,
+                                });
+                            }
+                        }
+                    }
+                    for (name, argument_ty) in &arguments.keyword {
+                        let Some(field) = fields
+                            .iter()
+                            .find(|field| field.name.as_str() == name.as_str())
+                        else {
+                            errors.push(CallArgumentError::UnknownKeywordArgument {
+                                name: name.clone(),
+                            });
+                            continue;
+                        };
+                        if !argument_ty.is_assignable_to(db, field.ty) {
+                            errors.push(CallArgumentError::InvalidKeywordArgumentType {
+                                name: name.clone(),
+                                parameter_ty: field.ty,
+                                argument_ty: *argument_ty,
+                            });
+                        }
+                    }
+                    return if errors.is_empty() {
+                        CallOutcome::callable(instance_ty)
+                    } else {
+                        CallOutcome::InvalidArguments {
+                            callable_ty: self,
+                            return_ty: instance_ty,
+                            errors: errors.into_boxed_slice(),
+                        }
+                    };
+                }
+                // A `__new__` annotated as returning something that isn't an instance of
+                // this class determines the result of the call, and at runtime `__init__`
+                // is then not invoked at all, so its checks don't apply.
+                if let Some(new_return_ty) = class.dunder_new_return_ty(db) {
+                    let constructs_instance = match new_return_ty {
+                        // `Self` and generic return annotations aren't modeled yet; assume
+                        // they construct an instance of the class.
+                        Type::Todo | Type::Unknown | Type::KnownInstance(_) => true,
+                        Type::Instance(instance) => instance.is_instance_of(db, class),
+                        _ => false,
+                    };
+                    if !constructs_instance {
+                        return CallOutcome::callable(new_return_ty);
+                    }
+                }
+                let return_ty = match class.known(db) {
                     // If the class is the builtin-bool class (for example `bool(1)`), we try to
                     // return the specific truthiness value of the input arg, `Literal[True]` for
                     // the example above.
-                    Some(KnownClass::Bool) => arg_types
-                        .first()
+                    Some(KnownClass::Bool) => arguments
+                        .first_positional()
                         .map(|arg| arg.bool(db).into_type(db))
                         .unwrap_or(Type::BooleanLiteral(false)),
                     _ => Type::Instance(InstanceType { class }),
-                })
+                };
+                let mut errors = vec![];
+                match class.init_method(db) {
+                    ClassInit::Method(init) => {
+                        if let Some((required, maximum)) = init.undecorated_positional_arity(db) {
+                            // `self` is bound to the freshly created instance.
+                            let required = required.saturating_sub(1);
+                            let maximum = maximum.saturating_sub(1);
+                            let positional_types = arguments.positional_types();
+                            if arguments.has_variadic() {
+                                // Not countable.
+                            } else if positional_types.len() > maximum {
+                                errors.push(CallArgumentError::TooManyArguments {
+                                    expected: maximum,
+                                    received: positional_types.len(),
+                                });
+                            } else if positional_types.len() < required
+                                && !arguments.has_keywords()
+                            {
+                                errors.push(CallArgumentError::TooFewArguments {
+                                    expected: required,
+                                    received: positional_types.len(),
+                                });
+                            } else {
+                                let parameter_types =
+                                    init.signature(db).positional_parameter_types();
+                                for (argument_ty, parameter_ty) in
+                                    positional_types.iter().zip(parameter_types.iter().skip(1))
+                                {
+                                    // TODO: a `TypeVar` parameter (generic `__init__`)
+                                    // can't be checked yet.
+                                    if matches!(parameter_ty, Type::KnownInstance(_)) {
+                                        continue;
+                                    }
+                                    if !argument_ty.is_assignable_to(db, *parameter_ty) {
+                                        errors.push(CallArgumentError::InvalidArgumentType {
+                                            parameter_ty: *parameter_ty,
+                                            argument_ty: *argument_ty,
+                                        });
+                                    }
+                                }
+                            }
+                        }
+                        arguments.check_keywords(db, init.signature(db), &mut errors);
+                    }
+                    ClassInit::Object => {
+                        // `object.__init__` only rejects arguments when `__new__` is not
+                        // overridden; validating calls against an overridden `__new__` is
+                        // still a TODO.
+                        if !arguments.is_empty() && !class.overrides_dunder_new(db) {
+                            errors.push(CallArgumentError::TooManyArguments {
+                                expected: 0,
+                                received: arguments.count(),
+                            });
+                        }
+                    }
+                    ClassInit::Unknown => {}
+                }
+                if errors.is_empty() {
+                    CallOutcome::callable(return_ty)
+                } else {
+                    CallOutcome::InvalidArguments {
+                        callable_ty: self,
+                        return_ty,
+                        errors: errors.into_boxed_slice(),
+                    }
+                }
+            }
+
+            Type::SubclassOf(SubclassOfType { class }) => {
+                // A value of type `type[C]` is `C` or some subclass of it; calling it
+                // constructs an instance of that class. We don't validate the arguments
+                // against `C.__init__`, since a subclass may override it.
+                CallOutcome::callable(Type::instance(class))
             }
 
-            instance_ty @ Type::Instance(_) => {
-                let args = std::iter::once(self)
-                    .chain(arg_types.iter().copied())
-                    .collect::<Vec<_>>();
-                match instance_ty.call_dunder(db, "__call__", &args) {
+            instance_ty @ Type::Instance(InstanceType { class }) => {
+                // When `__call__` is a plain method with a countable parameter list, the
+                // arguments can be validated against it; its first parameter is bound to
+                // the instance itself.
+                if let Symbol::Type(Type::FunctionLiteral(dunder_call), Boundness::Bound) =
+                    class.class_member(db, "__call__")
+                {
+                    let return_ty = dunder_call.signature(db).return_ty;
+                    let mut errors = vec![];
+                    if let Some((required, maximum)) =
+                        dunder_call.undecorated_positional_arity(db)
+                    {
+                        let required = required.saturating_sub(1);
+                        let maximum = maximum.saturating_sub(1);
+                        let positional_types = arguments.positional_types();
+                        if arguments.has_variadic() {
+                            // Not countable.
+                        } else if positional_types.len() > maximum {
+                            errors.push(CallArgumentError::TooManyArguments {
+                                expected: maximum,
+                                received: positional_types.len(),
+                            });
+                        } else if positional_types.len() < required && !arguments.has_keywords() {
+                            errors.push(CallArgumentError::TooFewArguments {
+                                expected: required,
+                                received: positional_types.len(),
+                            });
+                        } else {
+                            let parameter_types =
+                                dunder_call.signature(db).positional_parameter_types();
+                            for (argument_ty, parameter_ty) in
+                                positional_types.iter().zip(parameter_types.iter().skip(1))
+                            {
+                                // TODO: a `TypeVar` parameter (generic `__call__`)
+                                // can't be checked yet.
+                                if matches!(parameter_ty, Type::KnownInstance(_)) {
+                                    continue;
+                                }
+                                if !argument_ty.is_assignable_to(db, *parameter_ty) {
+                                    errors.push(CallArgumentError::InvalidArgumentType {
+                                        parameter_ty: *parameter_ty,
+                                        argument_ty: *argument_ty,
+                                    });
+                                }
+                            }
+                        }
+                    }
+                    arguments.check_keywords(db, dunder_call.signature(db), &mut errors);
+                    if !errors.is_empty() {
+                        return CallOutcome::InvalidArguments {
+                            callable_ty: self,
+                            return_ty,
+                            errors: errors.into_boxed_slice(),
+                        };
+                    }
+                }
+                match instance_ty.call_dunder(db, "__call__", &arguments.with_self(self)) {
                     CallDunderResult::CallOutcome(CallOutcome::NotCallable { .. }) => {
                         // Turn "`<type of illegal '__call__'>` not callable" into
                         // "`X` not callable"
@@ -1338,7 +2376,7 @@ impl<'db> Type<'db> {
                 union
                     .elements(db)
                     .iter()
-                    .map(|elem| elem.call(db, arg_types)),
+                    .map(|elem| elem.call(db, arguments)),
             ),
 
             // TODO: intersection types
@@ -1353,14 +2391,14 @@ impl<'db> Type<'db> {
         self,
         db: &'db dyn Db,
         name: &str,
-        arg_types: &[Type<'db>],
+        arguments: &CallArguments<'db>,
     ) -> CallDunderResult<'db> {
         match self.to_meta_type(db).member(db, name) {
             Symbol::Type(callable_ty, Boundness::Bound) => {
-                CallDunderResult::CallOutcome(callable_ty.call(db, arg_types))
+                CallDunderResult::CallOutcome(callable_ty.call(db, arguments))
             }
             Symbol::Type(callable_ty, Boundness::PossiblyUnbound) => {
-                CallDunderResult::PossiblyUnbound(callable_ty.call(db, arg_types))
+                CallDunderResult::PossiblyUnbound(callable_ty.call(db, arguments))
             }
             Symbol::Unbound => CallDunderResult::MethodNotAvailable,
         }
@@ -1381,24 +2419,39 @@ impl<'db> Type<'db> {
             };
         }
 
+        if let Type::Iterator(iterator) = self {
+            return IterationOutcome::Iterable {
+                element_ty: iterator.yields(db),
+            };
+        }
+
+        if let Type::GenericInstance(generic) = self {
+            if let Some(element_ty) = generic.iteration_element_ty(db) {
+                return IterationOutcome::Iterable { element_ty };
+            }
+            return Type::instance(generic.class(db)).iterate(db);
+        }
+
         if matches!(self, Type::Unknown | Type::Any | Type::Todo) {
             // Explicit handling of `Unknown` and `Any` necessary until `type[Unknown]` and
             // `type[Any]` are not defined as `Todo` anymore.
             return IterationOutcome::Iterable { element_ty: self };
         }
 
-        let dunder_iter_result = self.call_dunder(db, "__iter__", &[self]);
+        let dunder_iter_result =
+            self.call_dunder(db, "__iter__", &CallArguments::positional([self]));
         match dunder_iter_result {
             CallDunderResult::CallOutcome(ref call_outcome)
             | CallDunderResult::PossiblyUnbound(ref call_outcome) => {
                 let Some(iterator_ty) = call_outcome.return_ty(db) else {
                     return IterationOutcome::NotIterable {
                         not_iterable_ty: self,
+                        reason: NotIterableReason::DunderIterNotCallable,
                     };
                 };
 
                 return if let Some(element_ty) = iterator_ty
-                    .call_dunder(db, "__next__", &[iterator_ty])
+                    .call_dunder(db, "__next__", &CallArguments::positional([iterator_ty]))
                     .return_ty(db)
                 {
                     if matches!(dunder_iter_result, CallDunderResult::PossiblyUnbound(..)) {
@@ -1412,6 +2465,7 @@ impl<'db> Type<'db> {
                 } else {
                     IterationOutcome::NotIterable {
                         not_iterable_ty: self,
+                        reason: NotIterableReason::NoDunderNext { iterator_ty },
                     }
                 };
             }
@@ -1422,39 +2476,126 @@ impl<'db> Type<'db> {
         // classes that define `__getitem__` are also iterable,
         // even if they do not define `__iter__`.
         //
-        // TODO(Alex) this is only valid if the `__getitem__` method is annotated as
-        // accepting `int` or `SupportsIndex`
-        if let Some(element_ty) = self
-            .call_dunder(db, "__getitem__", &[self, KnownClass::Int.to_instance(db)])
-            .return_ty(db)
+        // This only holds if the `__getitem__` method accepts `int` keys; a dict-like
+        // `__getitem__(key: str)` does not make the object iterable.
+        let dunder_getitem_result = self.call_dunder(
+            db,
+            "__getitem__",
+            &CallArguments::positional([self, KnownClass::Int.to_instance(db)]),
+        );
+        if let CallDunderResult::CallOutcome(CallOutcome::InvalidArguments { .. }) =
+            dunder_getitem_result
         {
+            return IterationOutcome::NotIterable {
+                not_iterable_ty: self,
+                reason: NotIterableReason::DunderGetitemDoesNotAcceptInt,
+            };
+        }
+        if let Some(element_ty) = dunder_getitem_result.return_ty(db) {
             IterationOutcome::Iterable { element_ty }
         } else {
             IterationOutcome::NotIterable {
                 not_iterable_ty: self,
+                reason: NotIterableReason::NoDunderIter,
             }
         }
     }
 
-    #[must_use]
-    pub fn to_instance(&self, db: &'db dyn Db) -> Type<'db> {
-        match self {
-            Type::Any => Type::Any,
-            Type::Todo => Type::Todo,
-            Type::Unknown => Type::Unknown,
-            Type::Never => Type::Never,
-            Type::ClassLiteral(ClassLiteralType { class }) => Type::instance(*class),
-            Type::SubclassOf(SubclassOfType { class }) => Type::instance(*class),
-            Type::Union(union) => union.map(db, |element| element.to_instance(db)),
-            // TODO: we can probably do better here: --Alex
-            Type::Intersection(_) => Type::Todo,
-            // TODO: calling `.to_instance()` on any of these should result in a diagnostic,
-            // since they already indicate that the object is an instance of some kind:
-            Type::BooleanLiteral(_)
-            | Type::BytesLiteral(_)
-            | Type::FunctionLiteral(_)
-            | Type::Instance(_)
-            | Type::KnownInstance(_)
+    /// Given the type of an object that is iterated over with `async for`,
+    /// return the type of objects that are yielded by that iteration.
+    ///
+    /// Async iteration resolves `__aiter__` to get an async iterator, calls `__anext__` on
+    /// it, and awaits the result.
+    fn iterate_async(self, db: &'db dyn Db) -> IterationOutcome<'db> {
+        if matches!(self, Type::Unknown | Type::Any | Type::Todo) {
+            return IterationOutcome::Iterable { element_ty: self };
+        }
+
+        let dunder_aiter_result =
+            self.call_dunder(db, "__aiter__", &CallArguments::positional([self]));
+        match dunder_aiter_result {
+            CallDunderResult::CallOutcome(ref call_outcome)
+            | CallDunderResult::PossiblyUnbound(ref call_outcome) => {
+                let Some(iterator_ty) = call_outcome.return_ty(db) else {
+                    return IterationOutcome::NotAsyncIterable {
+                        not_iterable_ty: self,
+                        reason: NotAsyncIterableReason::DunderAiterNotCallable,
+                    };
+                };
+
+                let Some(awaitable_ty) = iterator_ty
+                    .call_dunder(db, "__anext__", &CallArguments::positional([iterator_ty]))
+                    .return_ty(db)
+                else {
+                    return IterationOutcome::NotAsyncIterable {
+                        not_iterable_ty: self,
+                        reason: NotAsyncIterableReason::NoDunderAnext { iterator_ty },
+                    };
+                };
+
+                let Some(element_ty) = awaitable_ty.awaited(db) else {
+                    return IterationOutcome::NotAsyncIterable {
+                        not_iterable_ty: self,
+                        reason: NotAsyncIterableReason::DunderAnextNotAwaitable { awaitable_ty },
+                    };
+                };
+
+                if matches!(dunder_aiter_result, CallDunderResult::PossiblyUnbound(..)) {
+                    IterationOutcome::PossiblyUnboundDunderIter {
+                        iterable_ty: self,
+                        element_ty,
+                    }
+                } else {
+                    IterationOutcome::Iterable { element_ty }
+                }
+            }
+            CallDunderResult::MethodNotAvailable => IterationOutcome::NotAsyncIterable {
+                not_iterable_ty: self,
+                reason: NotAsyncIterableReason::NoDunderAiter,
+            },
+        }
+    }
+
+    /// The type of the value produced by `await`-ing an object of this type, or `None` if
+    /// the object is not awaitable.
+    fn awaited(self, db: &'db dyn Db) -> Option<Type<'db>> {
+        match self {
+            Type::Coroutine(coroutine) => Some(coroutine.result(db)),
+            Type::Unknown | Type::Any | Type::Todo => Some(self),
+            _ => match self.call_dunder(db, "__await__", &CallArguments::positional([self])) {
+                CallDunderResult::MethodNotAvailable => None,
+                // The awaited value is the value returned by the generator that `__await__`
+                // returns; representing that requires generics.
+                _ => Some(Type::Todo),
+            },
+        }
+    }
+
+    #[must_use]
+    pub fn to_instance(&self, db: &'db dyn Db) -> Type<'db> {
+        match self {
+            Type::Any => Type::Any,
+            Type::Todo => Type::Todo,
+            Type::Unknown => Type::Unknown,
+            Type::Never => Type::Never,
+            Type::ClassLiteral(ClassLiteralType { class }) => Type::instance(*class),
+            Type::SubclassOf(SubclassOfType { class }) => Type::instance(*class),
+            Type::Union(union) => union.map(db, |element| element.to_instance(db)),
+            // TODO: we can probably do better here: --Alex
+            Type::Intersection(_) => Type::Todo,
+            // TODO: calling `.to_instance()` on any of these should result in a diagnostic,
+            // since they already indicate that the object is an instance of some kind:
+            Type::BooleanLiteral(_)
+            | Type::BytesLiteral(_)
+            | Type::FunctionLiteral(_)
+            | Type::Instance(_)
+            | Type::GenericInstance(_)
+            | Type::Iterator(_)
+            | Type::Coroutine(_)
+            | Type::BoundSuper(_)
+            | Type::Callable(_)
+            | Type::TypeGuard(_)
+            | Type::KnownInstance(_)
             | Type::ModuleLiteral(_)
             | Type::IntLiteral(_)
             | Type::StringLiteral(_)
@@ -1478,6 +2619,10 @@ impl<'db> Type<'db> {
             Type::Unknown => Type::Unknown,
             // TODO map this to a new `Type::TypeVar` variant
             Type::KnownInstance(KnownInstanceType::TypeVar(_)) => *self,
+            // A `ParamSpec` is only valid as the first argument to `Callable`, which
+            // handles it before consulting this method; passing it through lets
+            // `infer_callable_type_expression` recognize it.
+            Type::KnownInstance(KnownInstanceType::ParamSpec(_)) => *self,
             _ => Type::Todo,
         }
     }
@@ -1529,6 +2674,19 @@ impl<'db> Type<'db> {
             Type::Instance(InstanceType { class }) => {
                 Type::SubclassOf(SubclassOfType { class: *class })
             }
+            Type::GenericInstance(generic) => Type::SubclassOf(SubclassOfType {
+                class: generic.class(db),
+            }),
+            Type::Iterator(iterator) => Type::SubclassOf(SubclassOfType {
+                class: iterator.class(db),
+            }),
+            Type::Coroutine(coroutine) => Type::SubclassOf(SubclassOfType {
+                class: coroutine.class(db),
+            }),
+            Type::BoundSuper(_) => KnownClass::Super.to_class_literal(db),
+            // We don't know the class of a callable object, only its signature.
+            Type::Callable(_) => KnownClass::Type.to_instance(db),
+            Type::TypeGuard(_) => KnownClass::Bool.to_class_literal(db),
             Type::KnownInstance(known_instance) => known_instance.class().to_class_literal(db),
             Type::Union(union) => union.map(db, |ty| ty.to_meta_type(db)),
             Type::BooleanLiteral(_) => KnownClass::Bool.to_class_literal(db),
@@ -1624,27 +2782,53 @@ pub enum KnownClass {
     Bool,
     Object,
     Bytes,
+    ByteArray,
+    MemoryView,
     Type,
     Int,
     Float,
+    Complex,
     Str,
     List,
     Tuple,
     Set,
+    FrozenSet,
     Dict,
     Slice,
+    Range,
+    Zip,
+    Enumerate,
+    Map,
+    Filter,
+    Classmethod,
+    Staticmethod,
+    Super,
+    Property,
+    EllipsisType,       // `types.EllipsisType` on Python >= 3.10, `builtins.ellipsis` before
+    NotImplementedType, // `types.NotImplementedType` re-exports `builtins._NotImplementedType`
+    // enum
+    Enum,
+    IntEnum,
+    StrEnum,
+    Flag,
+    IntFlag,
     // Types
     GenericAlias,
     ModuleType,
     FunctionType,
+    GeneratorType,
+    CoroutineType,
     // Typeshed
     NoneType, // Part of `types` for Python >= 3.10
     // Typing
     SpecialForm,
     TypeVar,
+    ParamSpec,
     NoDefaultType,
     // sys
     VersionInfo,
+    // warnings (re-exported by `typing_extensions` for Python < 3.13)
+    Deprecated,
 }
 
 impl<'db> KnownClass {
@@ -1653,21 +2837,47 @@ impl<'db> KnownClass {
             Self::Bool => "bool",
             Self::Object => "object",
             Self::Bytes => "bytes",
+            Self::ByteArray => "bytearray",
+            Self::MemoryView => "memoryview",
             Self::Tuple => "tuple",
             Self::Int => "int",
             Self::Float => "float",
+            Self::Complex => "complex",
             Self::Str => "str",
             Self::Set => "set",
+            Self::FrozenSet => "frozenset",
             Self::Dict => "dict",
             Self::List => "list",
             Self::Type => "type",
             Self::Slice => "slice",
+            Self::Range => "range",
+            Self::Zip => "zip",
+            Self::Enumerate => "enumerate",
+            Self::Map => "map",
+            Self::Filter => "filter",
+            Self::Classmethod => "classmethod",
+            Self::Staticmethod => "staticmethod",
+            Self::Super => "super",
+            Self::Property => "property",
+            // The class is only named `ellipsis` in the `builtins` stubs (and only on
+            // Python < 3.10); `types.EllipsisType` is the supported runtime spelling.
+            Self::EllipsisType => "ellipsis",
+            // Same story: `types.NotImplementedType` is an alias for this private class.
+            Self::NotImplementedType => "_NotImplementedType",
+            Self::Enum => "Enum",
+            Self::IntEnum => "IntEnum",
+            Self::StrEnum => "StrEnum",
+            Self::Flag => "Flag",
+            Self::IntFlag => "IntFlag",
             Self::GenericAlias => "GenericAlias",
             Self::ModuleType => "ModuleType",
             Self::FunctionType => "FunctionType",
+            Self::GeneratorType => "GeneratorType",
+            Self::CoroutineType => "CoroutineType",
             Self::NoneType => "NoneType",
             Self::SpecialForm => "_SpecialForm",
             Self::TypeVar => "TypeVar",
+            Self::ParamSpec => "ParamSpec",
             Self::NoDefaultType => "_NoDefaultType",
             // This is the name the type of `sys.version_info` has in typeshed,
             // which is different to what `type(sys.version_info).__name__` is at runtime.
@@ -1675,6 +2885,7 @@ impl<'db> KnownClass {
             // which is impossible to replicate in the stubs since the sole instance of the class
             // also has that name in the `sys` module.)
             Self::VersionInfo => "_version_info",
+            Self::Deprecated => "deprecated",
         }
     }
 
@@ -1694,23 +2905,48 @@ impl<'db> KnownClass {
             Self::Bool
             | Self::Object
             | Self::Bytes
+            | Self::ByteArray
+            | Self::MemoryView
             | Self::Type
             | Self::Int
             | Self::Float
+            | Self::Complex
             | Self::Str
             | Self::List
             | Self::Tuple
             | Self::Set
+            | Self::FrozenSet
             | Self::Dict
-            | Self::Slice => CoreStdlibModule::Builtins,
+            | Self::Slice
+            | Self::Range
+            | Self::Zip
+            | Self::Enumerate
+            | Self::Map
+            | Self::Filter
+            | Self::Classmethod
+            | Self::Staticmethod
+            | Self::Super
+            | Self::Property
+            | Self::EllipsisType
+            | Self::NotImplementedType => CoreStdlibModule::Builtins,
+            Self::Enum | Self::IntEnum | Self::StrEnum | Self::Flag | Self::IntFlag => {
+                CoreStdlibModule::Enum
+            }
             Self::VersionInfo => CoreStdlibModule::Sys,
-            Self::GenericAlias | Self::ModuleType | Self::FunctionType => CoreStdlibModule::Types,
+            Self::GenericAlias
+            | Self::ModuleType
+            | Self::FunctionType
+            | Self::GeneratorType
+            | Self::CoroutineType => CoreStdlibModule::Types,
             Self::NoneType => CoreStdlibModule::Typeshed,
-            Self::SpecialForm | Self::TypeVar => CoreStdlibModule::Typing,
+            Self::SpecialForm | Self::TypeVar | Self::ParamSpec => CoreStdlibModule::Typing,
             // TODO when we understand sys.version_info, we will need an explicit fallback here,
             // because typing_extensions has a 3.13+ re-export for the `typing.NoDefault`
             // singleton, but not for `typing._NoDefaultType`
             Self::NoDefaultType => CoreStdlibModule::TypingExtensions,
+            // `warnings.deprecated` only exists on Python >= 3.13;
+            // `typing_extensions.deprecated` is available on every version.
+            Self::Deprecated => CoreStdlibModule::TypingExtensions,
         }
     }
 
@@ -1718,26 +2954,51 @@ impl<'db> KnownClass {
     ///
     /// A singleton class is a class where it is known that only one instance can ever exist at runtime.
     const fn is_singleton(self) -> bool {
-        // TODO there are other singleton types (EllipsisType, NotImplementedType)
         match self {
-            Self::NoneType | Self::NoDefaultType | Self::VersionInfo => true,
+            Self::NoneType
+            | Self::EllipsisType
+            | Self::NotImplementedType
+            | Self::NoDefaultType
+            | Self::VersionInfo => true,
             Self::Bool
             | Self::Object
             | Self::Bytes
+            | Self::ByteArray
+            | Self::MemoryView
             | Self::Tuple
             | Self::Int
             | Self::Float
+            | Self::Complex
             | Self::Str
             | Self::Set
+            | Self::FrozenSet
             | Self::Dict
             | Self::List
             | Self::Type
             | Self::Slice
+            | Self::Range
+            | Self::Zip
+            | Self::Enumerate
+            | Self::Map
+            | Self::Filter
+            | Self::Classmethod
+            | Self::Staticmethod
+            | Self::Super
+            | Self::Property
+            | Self::Enum
+            | Self::IntEnum
+            | Self::StrEnum
+            | Self::Flag
+            | Self::IntFlag
             | Self::GenericAlias
             | Self::ModuleType
             | Self::FunctionType
+            | Self::GeneratorType
+            | Self::CoroutineType
             | Self::SpecialForm
-            | Self::TypeVar => false,
+            | Self::TypeVar
+            | Self::ParamSpec
+            | Self::Deprecated => false,
         }
     }
 
@@ -1749,22 +3010,47 @@ impl<'db> KnownClass {
             "bool" => Self::Bool,
             "object" => Self::Object,
             "bytes" => Self::Bytes,
+            "bytearray" => Self::ByteArray,
+            "memoryview" => Self::MemoryView,
             "tuple" => Self::Tuple,
             "type" => Self::Type,
             "int" => Self::Int,
             "float" => Self::Float,
+            "complex" => Self::Complex,
             "str" => Self::Str,
             "set" => Self::Set,
+            "frozenset" => Self::FrozenSet,
             "dict" => Self::Dict,
             "list" => Self::List,
             "slice" => Self::Slice,
+            "range" => Self::Range,
+            "ellipsis" | "EllipsisType" => Self::EllipsisType,
+            "_NotImplementedType" => Self::NotImplementedType,
+            "zip" => Self::Zip,
+            "enumerate" => Self::Enumerate,
+            "map" => Self::Map,
+            "filter" => Self::Filter,
+            "classmethod" => Self::Classmethod,
+            "staticmethod" => Self::Staticmethod,
+            "super" => Self::Super,
+            "property" => Self::Property,
+            "Enum" => Self::Enum,
+            "IntEnum" => Self::IntEnum,
+            "StrEnum" => Self::StrEnum,
+            "Flag" => Self::Flag,
+            "IntFlag" => Self::IntFlag,
             "GenericAlias" => Self::GenericAlias,
             "NoneType" => Self::NoneType,
             "ModuleType" => Self::ModuleType,
             "FunctionType" => Self::FunctionType,
+            "GeneratorType" => Self::GeneratorType,
+            "CoroutineType" => Self::CoroutineType,
+            "TypeVar" => Self::TypeVar,
+            "ParamSpec" => Self::ParamSpec,
             "_SpecialForm" => Self::SpecialForm,
             "_NoDefaultType" => Self::NoDefaultType,
             "_version_info" => Self::VersionInfo,
+            "deprecated" => Self::Deprecated,
             _ => return None,
         };
 
@@ -1781,23 +3067,49 @@ impl<'db> KnownClass {
             Self::Bool
             | Self::Object
             | Self::Bytes
+            | Self::ByteArray
+            | Self::MemoryView
             | Self::Type
             | Self::Int
             | Self::Float
+            | Self::Complex
             | Self::Str
             | Self::List
             | Self::Tuple
             | Self::Set
+            | Self::FrozenSet
             | Self::Dict
             | Self::Slice
+            | Self::Range
+            | Self::Zip
+            | Self::Enumerate
+            | Self::Map
+            | Self::Filter
+            | Self::Classmethod
+            | Self::Staticmethod
+            | Self::Super
+            | Self::Property
+            | Self::Enum
+            | Self::IntEnum
+            | Self::StrEnum
+            | Self::Flag
+            | Self::IntFlag
             | Self::GenericAlias
             | Self::ModuleType
             | Self::VersionInfo
-            | Self::FunctionType => module.name() == self.canonical_module().as_str(),
+            | Self::FunctionType
+            | Self::GeneratorType
+            | Self::CoroutineType => module.name() == self.canonical_module().as_str(),
             Self::NoneType => matches!(module.name().as_str(), "_typeshed" | "types"),
-            Self::SpecialForm | Self::TypeVar | Self::NoDefaultType => {
+            Self::EllipsisType | Self::NotImplementedType => {
+                matches!(module.name().as_str(), "builtins" | "types")
+            }
+            Self::SpecialForm | Self::TypeVar | Self::ParamSpec | Self::NoDefaultType => {
                 matches!(module.name().as_str(), "typing" | "typing_extensions")
             }
+            Self::Deprecated => {
+                matches!(module.name().as_str(), "warnings" | "typing_extensions")
+            }
         }
     }
 }
@@ -1807,8 +3119,26 @@ impl<'db> KnownClass {
 pub enum KnownInstanceType<'db> {
     /// The symbol `typing.Literal` (which can also be found as `typing_extensions.Literal`)
     Literal,
+    /// The symbol `typing.Protocol` (which can also be found as `typing_extensions.Protocol`)
+    Protocol,
+    /// The symbol `typing.Callable` (which can also be found as `typing_extensions.Callable`)
+    Callable,
+    /// The symbol `typing.TypeGuard` (which can also be found as `typing_extensions.TypeGuard`)
+    TypeGuard,
+    /// The symbol `typing.TypeIs` (which can also be found as `typing_extensions.TypeIs`)
+    TypeIs,
+    /// The symbol `typing.TypedDict` (which can also be found as `typing_extensions.TypedDict`)
+    TypedDict,
+    /// The symbol `typing.Optional` (which can also be found as `typing_extensions.Optional`)
+    Optional,
+    /// The symbol `typing.Union` (which can also be found as `typing_extensions.Union`)
+    Union,
+    /// The symbol `typing.Annotated` (which can also be found as `typing_extensions.Annotated`)
+    Annotated,
     /// A single instance of `typing.TypeVar`
     TypeVar(TypeVarInstance<'db>),
+    /// A single instance of `typing.ParamSpec`
+    ParamSpec(ParamSpecInstance<'db>),
     // TODO: fill this enum out with more special forms, etc.
 }
 
@@ -1816,7 +3146,16 @@ impl<'db> KnownInstanceType<'db> {
     pub const fn as_str(self) -> &'static str {
         match self {
             KnownInstanceType::Literal => "Literal",
+            KnownInstanceType::Protocol => "Protocol",
+            KnownInstanceType::Callable => "Callable",
+            KnownInstanceType::TypeGuard => "TypeGuard",
+            KnownInstanceType::TypeIs => "TypeIs",
+            KnownInstanceType::TypedDict => "TypedDict",
+            KnownInstanceType::Optional => "Optional",
+            KnownInstanceType::Union => "Union",
+            KnownInstanceType::Annotated => "Annotated",
             KnownInstanceType::TypeVar(_) => "TypeVar",
+            KnownInstanceType::ParamSpec(_) => "ParamSpec",
         }
     }
 
@@ -1824,7 +3163,16 @@ impl<'db> KnownInstanceType<'db> {
     pub const fn bool(self) -> Truthiness {
         match self {
             Self::Literal => Truthiness::AlwaysTrue,
+            Self::Protocol => Truthiness::AlwaysTrue,
+            Self::Callable => Truthiness::AlwaysTrue,
+            Self::TypeGuard => Truthiness::AlwaysTrue,
+            Self::TypeIs => Truthiness::AlwaysTrue,
+            Self::TypedDict => Truthiness::AlwaysTrue,
+            Self::Optional => Truthiness::AlwaysTrue,
+            Self::Union => Truthiness::AlwaysTrue,
+            Self::Annotated => Truthiness::AlwaysTrue,
             Self::TypeVar(_) => Truthiness::AlwaysTrue,
+            Self::ParamSpec(_) => Truthiness::AlwaysTrue,
         }
     }
 
@@ -1832,7 +3180,16 @@ impl<'db> KnownInstanceType<'db> {
     pub fn repr(self, db: &'db dyn Db) -> &'db str {
         match self {
             Self::Literal => "typing.Literal",
+            Self::Protocol => "typing.Protocol",
+            Self::Callable => "typing.Callable",
+            Self::TypeGuard => "typing.TypeGuard",
+            Self::TypeIs => "typing.TypeIs",
+            Self::TypedDict => "typing.TypedDict",
+            Self::Optional => "typing.Optional",
+            Self::Union => "typing.Union",
+            Self::Annotated => "typing.Annotated",
             Self::TypeVar(typevar) => typevar.name(db),
+            Self::ParamSpec(paramspec) => paramspec.name(db),
         }
     }
 
@@ -1840,7 +3197,17 @@ impl<'db> KnownInstanceType<'db> {
     pub const fn class(self) -> KnownClass {
         match self {
             Self::Literal => KnownClass::SpecialForm,
+            Self::Protocol => KnownClass::SpecialForm,
+            Self::Callable => KnownClass::SpecialForm,
+            Self::TypeGuard => KnownClass::SpecialForm,
+            Self::TypeIs => KnownClass::SpecialForm,
+            // `typing.TypedDict` is declared as a plain `object` in typeshed.
+            Self::TypedDict => KnownClass::Object,
+            Self::Optional => KnownClass::SpecialForm,
+            Self::Union => KnownClass::SpecialForm,
+            Self::Annotated => KnownClass::SpecialForm,
             Self::TypeVar(_) => KnownClass::TypeVar,
+            Self::ParamSpec(_) => KnownClass::ParamSpec,
         }
     }
 
@@ -1859,6 +3226,14 @@ impl<'db> KnownInstanceType<'db> {
         }
         match (module.name().as_str(), instance_name) {
             ("typing" | "typing_extensions", "Literal") => Some(Self::Literal),
+            ("typing" | "typing_extensions", "Protocol") => Some(Self::Protocol),
+            ("typing" | "typing_extensions", "Callable") => Some(Self::Callable),
+            ("typing" | "typing_extensions", "TypeGuard") => Some(Self::TypeGuard),
+            ("typing" | "typing_extensions", "TypeIs") => Some(Self::TypeIs),
+            ("typing" | "typing_extensions", "TypedDict") => Some(Self::TypedDict),
+            ("typing" | "typing_extensions", "Optional") => Some(Self::Optional),
+            ("typing" | "typing_extensions", "Union") => Some(Self::Union),
+            ("typing" | "typing_extensions", "Annotated") => Some(Self::Annotated),
             _ => None,
         }
     }
@@ -1866,6 +3241,9 @@ impl<'db> KnownInstanceType<'db> {
     fn member(self, db: &'db dyn Db, name: &str) -> Symbol<'db> {
         let ty = match (self, name) {
             (Self::TypeVar(typevar), "__name__") => Type::string_literal(db, typevar.name(db)),
+            (Self::ParamSpec(paramspec), "__name__") => {
+                Type::string_literal(db, paramspec.name(db))
+            }
             (Self::TypeVar(typevar), "__bound__") => typevar
                 .upper_bound(db)
                 .map(|ty| ty.to_meta_type(db))
@@ -1937,6 +3315,224 @@ pub enum TypeVarBoundOrConstraints<'db> {
     Constraints(TupleType<'db>),
 }
 
+/// Data regarding a single `ParamSpec`.
+///
+/// This is referenced by `KnownInstanceType::ParamSpec`. Like [`TypeVarInstance`], this must
+/// be a tracked struct because `ParamSpec` equivalence is by identity, not by value.
+#[salsa::tracked]
+pub struct ParamSpecInstance<'db> {
+    /// The name of this ParamSpec (e.g. `P`)
+    #[return_ref]
+    name: ast::name::Name,
+
+    /// The default parameter list for this ParamSpec
+    default_ty: Option<Type<'db>>,
+}
+
+/// The arguments of a call site, with each argument expression already inferred.
+///
+/// Syntactic calls provide positional arguments, keyword arguments, and `*`/`**`
+/// unpackings in any combination; implicit calls (dunder methods) are purely positional
+/// and can be built with [`CallArguments::positional`].
+#[derive(Debug, Clone, Default)]
+struct CallArguments<'db> {
+    /// Types of the plain positional arguments, in order.
+    positional: Vec<Type<'db>>,
+
+    /// Names and value types of the keyword arguments.
+    keyword: Vec<(ast::name::Name, Type<'db>)>,
+
+    /// Types of `*args` unpackings. How many positional arguments each one provides is
+    /// unknown, so their presence disables positional arity checks.
+    variadic: Vec<Type<'db>>,
+
+    /// Types of `**kwargs` unpackings. Which keywords each one provides is unknown, so
+    /// their presence disables checks for missing arguments.
+    keyword_variadic: Vec<Type<'db>>,
+}
+
+impl<'db> CallArguments<'db> {
+    /// Create call arguments consisting only of the given positional argument types.
+    fn positional(positional_tys: impl IntoIterator<Item = Type<'db>>) -> Self {
+        Self {
+            positional: positional_tys.into_iter().collect(),
+            ..Self::default()
+        }
+    }
+
+    /// Return a copy of these arguments with `self_ty` prepended as the first positional
+    /// argument, as when a method is called through an instance.
+    fn with_self(&self, self_ty: Type<'db>) -> Self {
+        let mut arguments = self.clone();
+        arguments.positional.insert(0, self_ty);
+        arguments
+    }
+
+    fn positional_types(&self) -> &[Type<'db>] {
+        &self.positional
+    }
+
+    fn first_positional(&self) -> Option<Type<'db>> {
+        self.positional.first().copied()
+    }
+
+    /// The type of the keyword argument `name`, if the call provides one.
+    fn keyword_ty(&self, name: &str) -> Option<Type<'db>> {
+        self.keyword
+            .iter()
+            .find(|(keyword_name, _)| keyword_name.as_str() == name)
+            .map(|(_, argument_ty)| *argument_ty)
+    }
+
+    /// Solve `typevar` from these arguments: the union of the types of all arguments
+    /// bound to a parameter annotated with exactly that typevar, or `None` if no
+    /// argument binds it.
+    fn solve_typevar(
+        &self,
+        db: &'db dyn Db,
+        signature: &Signature<'db>,
+        typevar: TypeVarInstance<'db>,
+    ) -> Option<Type<'db>> {
+        let typevar_annotation = Type::KnownInstance(KnownInstanceType::TypeVar(typevar));
+        let mut solutions = vec![];
+        for (argument_ty, parameter_ty) in self
+            .positional
+            .iter()
+            .zip(signature.positional_parameter_types())
+        {
+            if parameter_ty == typevar_annotation {
+                solutions.push(*argument_ty);
+            }
+        }
+        for (name, argument_ty) in &self.keyword {
+            if signature.keyword_parameter_ty(name.as_str()) == Some(typevar_annotation) {
+                solutions.push(*argument_ty);
+            }
+        }
+        if solutions.is_empty() {
+            None
+        } else {
+            Some(UnionType::from_elements(db, solutions))
+        }
+    }
+
+    /// Find the argument bound to a parameter annotated `Callable[P, ...]` for the given
+    /// `ParamSpec`, returning the parameter's callable annotation and the argument's type.
+    fn solve_param_spec(
+        &self,
+        db: &'db dyn Db,
+        signature: &Signature<'db>,
+        param_spec: ParamSpecInstance<'db>,
+    ) -> Option<(CallableType<'db>, Type<'db>)> {
+        for (argument_ty, parameter_ty) in self
+            .positional
+            .iter()
+            .zip(signature.positional_parameter_types())
+        {
+            if let Type::Callable(callable) = parameter_ty {
+                if callable.param_spec(db) == Some(param_spec) {
+                    return Some((callable, *argument_ty));
+                }
+            }
+        }
+        None
+    }
+
+    /// Total number of arguments at the call site, counting each unpacking as one.
+    fn count(&self) -> usize {
+        self.positional.len()
+            + self.keyword.len()
+            + self.variadic.len()
+            + self.keyword_variadic.len()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.count() == 0
+    }
+
+    fn has_variadic(&self) -> bool {
+        !self.variadic.is_empty()
+    }
+
+    fn has_keywords(&self) -> bool {
+        !self.keyword.is_empty() || !self.keyword_variadic.is_empty()
+    }
+
+    /// Check the keyword arguments against `signature`, appending an error for any
+    /// keyword that doesn't name a parameter and for any value that isn't assignable to
+    /// its parameter's annotated type.
+    fn check_keywords(
+        &self,
+        db: &'db dyn Db,
+        signature: &Signature<'db>,
+        errors: &mut Vec<CallArgumentError<'db>>,
+    ) {
+        for (name, argument_ty) in &self.keyword {
+            let Some(parameter_ty) = signature.keyword_parameter_ty(name.as_str()) else {
+                if !signature.has_variadic_keywords() {
+                    errors.push(CallArgumentError::UnknownKeywordArgument { name: name.clone() });
+                }
+                continue;
+            };
+            // TODO: a `TypeVar` parameter (generic function) can't be checked yet.
+            if matches!(parameter_ty, Type::KnownInstance(_)) {
+                continue;
+            }
+            if !argument_ty.is_assignable_to(db, parameter_ty) {
+                errors.push(CallArgumentError::InvalidKeywordArgumentType {
+                    name: name.clone(),
+                    parameter_ty,
+                    argument_ty: *argument_ty,
+                });
+            }
+        }
+        // A `**` unpacking must at least be a mapping. A `TypedDict` declares exactly which
+        // keys it provides, so each of its fields is checked against the matching parameter;
+        // for any other mapping the provided keywords are unknown, so nothing further is
+        // checked.
+        // TODO: for a `dict[str, V]`, check `V` against the parameters it may fill
+        // (requires generics).
+        for mapping_ty in self.keyword_variadic.iter().copied() {
+            if matches!(mapping_ty, Type::Unknown | Type::Any | Type::Todo) {
+                continue;
+            }
+            if let Type::Instance(InstanceType { class }) = mapping_ty {
+                if class.is_typed_dict(db) {
+                    for field in class.typed_dict_fields(db) {
+                        let Some(parameter_ty) =
+                            signature.keyword_parameter_ty(field.name.as_str())
+                        else {
+                            if !signature.has_variadic_keywords() {
+                                errors.push(CallArgumentError::UnknownKeywordArgument {
+                                    name: field.name.clone(),
+                                });
+                            }
+                            continue;
+                        };
+                        // TODO: a `TypeVar` parameter (generic function) can't be checked yet.
+                        if matches!(parameter_ty, Type::KnownInstance(_)) {
+                            continue;
+                        }
+                        if !field.ty.is_assignable_to(db, parameter_ty) {
+                            errors.push(CallArgumentError::InvalidKeywordArgumentType {
+                                name: field.name.clone(),
+                                parameter_ty,
+                                argument_ty: field.ty,
+                            });
+                        }
+                    }
+                    continue;
+                }
+            }
+            if mapping_ty.member(db, "keys").is_unbound() {
+                errors.push(CallArgumentError::NotAMapping {
+                    argument_ty: mapping_ty,
+                });
+            }
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 enum CallOutcome<'db> {
     Callable {
@@ -1945,10 +3541,20 @@ enum CallOutcome<'db> {
     RevealType {
         return_ty: Type<'db>,
         revealed_ty: Type<'db>,
+        /// Number of arguments at the call site; `reveal_type` expects exactly one.
+        received_arguments: usize,
     },
     NotCallable {
         not_callable_ty: Type<'db>,
     },
+    InvalidArguments {
+        callable_ty: Type<'db>,
+        return_ty: Type<'db>,
+        errors: Box<[CallArgumentError<'db>]>,
+    },
+    NoMatchingOverload {
+        callable_ty: Type<'db>,
+    },
     Union {
         called_ty: Type<'db>,
         outcomes: Box<[CallOutcome<'db>]>,
@@ -1971,10 +3577,15 @@ impl<'db> CallOutcome<'db> {
     }
 
     /// Create a new `CallOutcome::RevealType` with given revealed and return types.
-    fn revealed(return_ty: Type<'db>, revealed_ty: Type<'db>) -> CallOutcome<'db> {
+    fn revealed(
+        return_ty: Type<'db>,
+        revealed_ty: Type<'db>,
+        received_arguments: usize,
+    ) -> CallOutcome<'db> {
         CallOutcome::RevealType {
             return_ty,
             revealed_ty,
+            received_arguments,
         }
     }
 
@@ -1996,8 +3607,11 @@ impl<'db> CallOutcome<'db> {
             Self::RevealType {
                 return_ty,
                 revealed_ty: _,
+                received_arguments: _,
             } => Some(*return_ty),
             Self::NotCallable { not_callable_ty: _ } => None,
+            Self::InvalidArguments { return_ty, .. } => Some(*return_ty),
+            Self::NoMatchingOverload { .. } => Some(Type::Unknown),
             Self::Union {
                 outcomes,
                 called_ty: _,
@@ -2027,65 +3641,9 @@ impl<'db> CallOutcome<'db> {
     ) -> Type<'db> {
         match self.return_ty_result(db, node, diagnostics) {
             Ok(return_ty) => return_ty,
-            Err(NotCallableError::Type {
-                not_callable_ty,
-                return_ty,
-            }) => {
-                diagnostics.add(
-                    node,
-                    "call-non-callable",
-                    format_args!(
-                        "Object of type `{}` is not callable",
-                        not_callable_ty.display(db)
-                    ),
-                );
-                return_ty
-            }
-            Err(NotCallableError::UnionElement {
-                not_callable_ty,
-                called_ty,
-                return_ty,
-            }) => {
-                diagnostics.add(
-                    node,
-                    "call-non-callable",
-                    format_args!(
-                        "Object of type `{}` is not callable (due to union element `{}`)",
-                        called_ty.display(db),
-                        not_callable_ty.display(db),
-                    ),
-                );
-                return_ty
-            }
-            Err(NotCallableError::UnionElements {
-                not_callable_tys,
-                called_ty,
-                return_ty,
-            }) => {
-                diagnostics.add(
-                    node,
-                    "call-non-callable",
-                    format_args!(
-                        "Object of type `{}` is not callable (due to union elements {})",
-                        called_ty.display(db),
-                        not_callable_tys.display(db),
-                    ),
-                );
-                return_ty
-            }
-            Err(NotCallableError::PossiblyUnboundDunderCall {
-                callable_ty: called_ty,
-                return_ty,
-            }) => {
-                diagnostics.add(
-                    node,
-                    "call-non-callable",
-                    format_args!(
-                        "Object of type `{}` is not callable (possibly unbound `__call__` method)",
-                        called_ty.display(db)
-                    ),
-                );
-                return_ty
+            Err(error) => {
+                error.add_diagnostic(db, node, diagnostics);
+                error.return_ty()
             }
         }
     }
@@ -2102,18 +3660,142 @@ impl<'db> CallOutcome<'db> {
             Self::RevealType {
                 return_ty,
                 revealed_ty,
+                received_arguments,
             } => {
-                diagnostics.add(
-                    node,
-                    "revealed-type",
-                    format_args!("Revealed type is `{}`", revealed_ty.display(db)),
-                );
+                if *received_arguments != 1 {
+                    diagnostics.add(
+                        node,
+                        Rule::InvalidArguments,
+                        format_args!(
+                            "`reveal_type` expects exactly 1 positional argument, \
+                             got {received_arguments}"
+                        ),
+                    );
+                }
+                // With no argument there is nothing to reveal.
+                if *received_arguments > 0 {
+                    diagnostics.add(
+                        node,
+                        Rule::RevealedType,
+                        format_args!("Revealed type is `{}`", revealed_ty.display(db)),
+                    );
+                }
                 Ok(*return_ty)
             }
             Self::NotCallable { not_callable_ty } => Err(NotCallableError::Type {
                 not_callable_ty: *not_callable_ty,
                 return_ty: Type::Unknown,
             }),
+            Self::InvalidArguments {
+                callable_ty,
+                return_ty,
+                errors,
+            } => {
+                for error in errors {
+                    match error {
+                        CallArgumentError::WrongArity { expected, received } => {
+                            diagnostics.add(
+                                node,
+                                Rule::InvalidArguments,
+                                format_args!(
+                                    "Object of type `{}` expects {expected} positional \
+                                     argument{}, got {received}",
+                                    callable_ty.display(db),
+                                    if *expected == 1 { "" } else { "s" },
+                                ),
+                            );
+                        }
+                        CallArgumentError::TooFewArguments { expected, received } => {
+                            diagnostics.add(
+                                node,
+                                Rule::TooFewArguments,
+                                format_args!(
+                                    "Object of type `{}` expects at least {expected} positional \
+                                     argument{}, got {received}",
+                                    callable_ty.display(db),
+                                    if *expected == 1 { "" } else { "s" },
+                                ),
+                            );
+                        }
+                        CallArgumentError::TooManyArguments { expected, received } => {
+                            diagnostics.add(
+                                node,
+                                Rule::TooManyArguments,
+                                format_args!(
+                                    "Object of type `{}` expects at most {expected} positional \
+                                     argument{}, got {received}",
+                                    callable_ty.display(db),
+                                    if *expected == 1 { "" } else { "s" },
+                                ),
+                            );
+                        }
+                        CallArgumentError::InvalidArgumentType {
+                            parameter_ty,
+                            argument_ty,
+                        } => {
+                            diagnostics.add(
+                                node,
+                                Rule::InvalidArgumentType,
+                                format_args!(
+                                    "Argument of type `{}` is not assignable to parameter of \
+                                     type `{}`",
+                                    argument_ty.display(db),
+                                    parameter_ty.display(db),
+                                ),
+                            );
+                        }
+                        CallArgumentError::UnknownKeywordArgument { name } => {
+                            diagnostics.add(
+                                node,
+                                Rule::InvalidArguments,
+                                format_args!(
+                                    "Object of type `{}` has no parameter named `{name}`",
+                                    callable_ty.display(db),
+                                ),
+                            );
+                        }
+                        CallArgumentError::InvalidKeywordArgumentType {
+                            name,
+                            parameter_ty,
+                            argument_ty,
+                        } => {
+                            diagnostics.add(
+                                node,
+                                Rule::InvalidArgumentType,
+                                format_args!(
+                                    "Argument of type `{}` is not assignable to parameter \
+                                     `{name}` of type `{}`",
+                                    argument_ty.display(db),
+                                    parameter_ty.display(db),
+                                ),
+                            );
+                        }
+                        CallArgumentError::NotAMapping { argument_ty } => {
+                            diagnostics.add(
+                                node,
+                                Rule::InvalidArguments,
+                                format_args!(
+                                    "Object of type `{}` is not a mapping and cannot be \
+                                     unpacked with `**`",
+                                    argument_ty.display(db),
+                                ),
+                            );
+                        }
+                    }
+                }
+                Ok(*return_ty)
+            }
+            Self::NoMatchingOverload { callable_ty } => {
+                diagnostics.add(
+                    node,
+                    Rule::NoMatchingOverload,
+                    format_args!(
+                        "No overload of `{}` matches arguments",
+                        callable_ty.display(db)
+                    ),
+                );
+                Ok(Type::Unknown)
+            }
             Self::PossiblyUnboundDunderCall {
                 called_ty,
                 call_outcome,
@@ -2137,6 +3819,7 @@ impl<'db> CallOutcome<'db> {
                         Self::RevealType {
                             return_ty,
                             revealed_ty: _,
+                            received_arguments: _,
                         } => {
                             if revealed {
                                 *return_ty
@@ -2156,6 +3839,9 @@ impl<'db> CallOutcome<'db> {
                         not_callable_ty: elem,
                         called_ty: *called_ty,
                         return_ty,
+                        // `Type::call` has no access to the AST; callers that can map the
+                        // element back to a sub-expression fill the span in afterwards.
+                        span: None,
                     }),
                     _ if not_callable.len() == outcomes.len() => Err(NotCallableError::Type {
                         not_callable_ty: *called_ty,
@@ -2200,6 +3886,10 @@ enum NotCallableError<'db> {
         not_callable_ty: Type<'db>,
         called_ty: Type<'db>,
         return_ty: Type<'db>,
+        /// The range of the sub-expression of the callee that produced the non-callable
+        /// element, if one could be identified; the diagnostic is anchored on it so that
+        /// the precise culprit is underlined rather than the whole callee.
+        span: Option<TextRange>,
     },
     /// Multiple (but not all) union elements are not callable.
     UnionElements {
@@ -2241,6 +3931,113 @@ impl<'db> NotCallableError<'db> {
             } => *called_ty,
         }
     }
+
+    /// Emit the `call-non-callable` diagnostic for this error, anchored on `node` unless
+    /// the error carries a more precise span.
+    fn add_diagnostic(
+        &self,
+        db: &'db dyn Db,
+        node: ast::AnyNodeRef,
+        diagnostics: &mut TypeCheckDiagnosticsBuilder<'db>,
+    ) {
+        match self {
+            Self::Type {
+                not_callable_ty, ..
+            } => {
+                if not_callable_ty.callability(db) == Callability::DunderCallIsNone {
+                    diagnostics.add(
+                        node,
+                        Rule::CallNonCallable,
+                        format_args!(
+                            "Object of type `{}` is not callable \
+                             because its `__call__` attribute is set to `None`",
+                            not_callable_ty.display(db)
+                        ),
+                    );
+                } else {
+                    diagnostics.add(
+                        node,
+                        Rule::CallNonCallable,
+                        format_args!(
+                            "Object of type `{}` is not callable",
+                            not_callable_ty.display(db)
+                        ),
+                    );
+                }
+            }
+            Self::UnionElement {
+                not_callable_ty,
+                called_ty,
+                span,
+                ..
+            } => {
+                diagnostics.add_with_range(
+                    span.unwrap_or_else(|| node.range()),
+                    Rule::CallNonCallable,
+                    format_args!(
+                        "Object of type `{}` is not callable (due to union element `{}`)",
+                        called_ty.display(db),
+                        not_callable_ty.display(db),
+                    ),
+                );
+            }
+            Self::UnionElements {
+                not_callable_tys,
+                called_ty,
+                ..
+            } => {
+                diagnostics.add(
+                    node,
+                    Rule::CallNonCallable,
+                    format_args!(
+                        "Object of type `{}` is not callable (due to union elements {})",
+                        called_ty.display(db),
+                        not_callable_tys.display(db),
+                    ),
+                );
+            }
+            Self::PossiblyUnboundDunderCall {
+                callable_ty: called_ty,
+                ..
+            } => {
+                diagnostics.add(
+                    node,
+                    Rule::CallNonCallable,
+                    format_args!(
+                        "Object of type `{}` is not callable (possibly unbound `__call__` method)",
+                        called_ty.display(db)
+                    ),
+                );
+            }
+        }
+    }
+}
+
+/// A single way in which the arguments at a call site fail to match the parameters of a
+/// [`CallableType`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum CallArgumentError<'db> {
+    /// The number of positional arguments doesn't match the number of parameters.
+    WrongArity { expected: usize, received: usize },
+    /// The call provides fewer positional arguments than the callable requires.
+    TooFewArguments { expected: usize, received: usize },
+    /// The call provides more positional arguments than the callable accepts.
+    TooManyArguments { expected: usize, received: usize },
+    /// An argument type is not assignable to the corresponding parameter type.
+    InvalidArgumentType {
+        parameter_ty: Type<'db>,
+        argument_ty: Type<'db>,
+    },
+    /// A keyword argument doesn't name any parameter of the callable.
+    UnknownKeywordArgument { name: ast::name::Name },
+    /// A keyword argument type is not assignable to its parameter type.
+    InvalidKeywordArgumentType {
+        name: ast::name::Name,
+        parameter_ty: Type<'db>,
+        argument_ty: Type<'db>,
+    },
+    /// A `**` unpacked argument is not a mapping.
+    NotAMapping { argument_ty: Type<'db> },
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -2250,6 +4047,11 @@ enum IterationOutcome<'db> {
     },
     NotIterable {
         not_iterable_ty: Type<'db>,
+        reason: NotIterableReason<'db>,
+    },
+    NotAsyncIterable {
+        not_iterable_ty: Type<'db>,
+        reason: NotAsyncIterableReason<'db>,
     },
     PossiblyUnboundDunderIter {
         iterable_ty: Type<'db>,
@@ -2257,6 +4059,36 @@ enum IterationOutcome<'db> {
     },
 }
 
+/// Why a type is not iterable; each variant corresponds to one of the failure paths
+/// in [`Type::iterate`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum NotIterableReason<'db> {
+    /// The type has neither an `__iter__` method nor a `__getitem__` method.
+    NoDunderIter,
+    /// The type has an `__iter__` attribute, but it cannot be called.
+    DunderIterNotCallable,
+    /// The type's `__iter__` method returns an object with no `__next__` method.
+    NoDunderNext { iterator_ty: Type<'db> },
+    /// The type has no `__iter__` method, and its `__getitem__` method does not accept
+    /// `int` keys.
+    DunderGetitemDoesNotAcceptInt,
+}
+
+/// Why a type is not async-iterable; each variant corresponds to one of the failure paths
+/// in [`Type::iterate_async`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum NotAsyncIterableReason<'db> {
+    /// The type has no `__aiter__` method.
+    NoDunderAiter,
+    /// The type has an `__aiter__` attribute, but it cannot be called.
+    DunderAiterNotCallable,
+    /// The type's `__aiter__` method returns an object with no `__anext__` method.
+    NoDunderAnext { iterator_ty: Type<'db> },
+    /// The `__anext__` method of the type's async iterator returns a value that is not
+    /// awaitable.
+    DunderAnextNotAwaitable { awaitable_ty: Type<'db> },
+}
+
 impl<'db> IterationOutcome<'db> {
     fn unwrap_with_diagnostic(
         self,
@@ -2265,8 +4097,18 @@ impl<'db> IterationOutcome<'db> {
     ) -> Type<'db> {
         match self {
             Self::Iterable { element_ty } => element_ty,
-            Self::NotIterable { not_iterable_ty } => {
-                diagnostics.add_not_iterable(iterable_node, not_iterable_ty);
+            Self::NotIterable {
+                not_iterable_ty,
+                reason,
+            } => {
+                diagnostics.add_not_iterable(iterable_node, not_iterable_ty, reason);
+                Type::Unknown
+            }
+            Self::NotAsyncIterable {
+                not_iterable_ty,
+                reason,
+            } => {
+                diagnostics.add_not_async_iterable(iterable_node, not_iterable_ty, reason);
                 Type::Unknown
             }
             Self::PossiblyUnboundDunderIter {
@@ -2322,6 +4164,25 @@ impl From<bool> for Truthiness {
     }
 }
 
+/// Classification of whether objects of a given type can be called, as returned by
+/// [`Type::callability`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Callability {
+    /// Calling an object of this type is expected to succeed.
+    Callable,
+    /// Calling an object of this type is fine, but nothing is known about its signature or
+    /// return type (`Any`, `Unknown`, `Todo`).
+    UnknownSignature,
+    /// Calling an object of this type may fail: some inhabitants are callable and some are
+    /// not (a union with a non-callable element, or a possibly unbound `__call__` method).
+    PossiblyNotCallable,
+    /// Objects of this type are never callable.
+    NotCallable,
+    /// Objects of this type are never callable because their `__call__` attribute is
+    /// explicitly set to `None`.
+    DunderCallIsNone,
+}
+
 #[salsa::interned]
 pub struct FunctionType<'db> {
     /// name of the function at definition
@@ -2335,6 +4196,21 @@ pub struct FunctionType<'db> {
 
     /// types of all decorators on this function
     decorators: Box<[Type<'db>]>,
+
+    /// the message of a `@warnings.deprecated` decorator on this function, if any
+    #[return_ref]
+    deprecated: Option<Box<str>>,
+}
+
+/// Cycle recovery for [`FunctionType::signature()`]: for now, a todo signature
+/// TODO fixpoint iteration
+fn signature_cycle_recovery<'db>(
+    _db: &'db dyn Db,
+    _cycle: &salsa::Cycle,
+    _function: FunctionType<'db>,
+) -> Signature<'db> {
+    tracing::trace!("signature_cycle_recovery");
+    Signature::todo()
 }
 
 #[salsa::tracked]
@@ -2343,6 +4219,218 @@ impl<'db> FunctionType<'db> {
         self.decorators(db).contains(&decorator)
     }
 
+    /// Is this function decorated with `@classmethod`?
+    pub(crate) fn is_classmethod(self, db: &dyn Db) -> bool {
+        self.has_decorator(db, KnownClass::Classmethod.to_class_literal(db))
+    }
+
+    /// Is this function decorated with `@staticmethod`?
+    pub(crate) fn is_staticmethod(self, db: &dyn Db) -> bool {
+        self.has_decorator(db, KnownClass::Staticmethod.to_class_literal(db))
+    }
+
+    /// Is this function decorated with `@property`?
+    pub(crate) fn is_property(self, db: &dyn Db) -> bool {
+        self.has_decorator(db, KnownClass::Property.to_class_literal(db))
+    }
+
+    /// Is this function decorated with `@overload`?
+    pub(crate) fn is_overload(self, db: &'db dyn Db) -> bool {
+        self.decorators(db).iter().any(|decorator| {
+            decorator
+                .into_function_literal()
+                .is_some_and(|function| function.is_known(db, KnownFunction::Overload))
+        })
+    }
+
+    /// The ordered `@overload`-decorated definitions of this function, if any.
+    ///
+    /// At runtime only the last same-named definition in a scope is visible, so the earlier
+    /// overloads are collected from the AST of the enclosing suite. Functions defined in a
+    /// class body are skipped for now: they are usually accessed through an instance (or
+    /// through `super()`), which binds the first parameter implicitly, and we don't model
+    /// that binding yet.
+    fn overloads(self, db: &'db dyn Db) -> Vec<FunctionType<'db>> {
+        let body_scope = self.body_scope(db);
+        let file = body_scope.file(db);
+        let index = semantic_index(db, file);
+        let Some(mut enclosing_scope) = body_scope.scope(db).parent() else {
+            return vec![];
+        };
+        // A generic function's body scope is nested inside its type-parameter scope.
+        while index.scope(enclosing_scope).kind() == ScopeKind::Annotation {
+            let Some(parent) = index.scope(enclosing_scope).parent() else {
+                return vec![];
+            };
+            enclosing_scope = parent;
+        }
+        let suite = match index.scope(enclosing_scope).node() {
+            NodeWithScopeKind::Module => &parsed_module(db.upcast(), file).syntax().body,
+            NodeWithScopeKind::Function(function) => &function.node().body,
+            _ => return vec![],
+        };
+        let name = &body_scope.node(db).expect_function().name;
+        suite
+            .iter()
+            .filter_map(ast::Stmt::as_function_def_stmt)
+            .filter(|function_stmt| {
+                function_stmt.name.as_str() == name.as_str()
+                    && !function_stmt.decorator_list.is_empty()
+            })
+            .filter_map(|function_stmt| {
+                binding_ty(db, index.definition(function_stmt)).into_function_literal()
+            })
+            .filter(|function| function.is_overload(db))
+            .collect()
+    }
+
+    /// The first overload of this function whose parameters accept the given positional
+    /// argument types, if any. Overload selection only considers positional arguments so
+    /// far.
+    pub(crate) fn matching_overload(
+        self,
+        db: &'db dyn Db,
+        positional_types: &[Type<'db>],
+    ) -> Option<FunctionType<'db>> {
+        self.overloads(db).into_iter().find(|overload| {
+            let parameter_types = overload.overload_signature(db).positional_parameter_types();
+            parameter_types.len() == positional_types.len()
+                && positional_types.iter().zip(&parameter_types).all(
+                    |(argument_ty, parameter_ty)| argument_ty.is_assignable_to(db, *parameter_ty),
+                )
+        })
+    }
+
+    /// Is this function decorated with `@functools.singledispatch`?
+    pub(crate) fn is_singledispatch(self, db: &'db dyn Db) -> bool {
+        self.decorators(db).iter().any(|decorator| {
+            decorator
+                .into_function_literal()
+                .is_some_and(|function| function.is_known(db, KnownFunction::SingleDispatch))
+        })
+    }
+
+    /// The implementations registered for this `@singledispatch` function with
+    /// `@f.register`, paired with the type they dispatch on.
+    ///
+    /// Registrations are detected syntactically in the suite where the dispatcher is
+    /// defined, like [`overloads`](Self::overloads): the decorator is either a bare
+    /// `@f.register` (the dispatch type is read from the implementation's first parameter
+    /// annotation) or a call `@f.register(C)` (the dispatch type is an instance of `C`).
+    fn singledispatch_registrations(self, db: &'db dyn Db) -> Vec<(Type<'db>, FunctionType<'db>)> {
+        let body_scope = self.body_scope(db);
+        let file = body_scope.file(db);
+        let index = semantic_index(db, file);
+        let Some(mut enclosing_scope) = body_scope.scope(db).parent() else {
+            return vec![];
+        };
+        // A generic function's body scope is nested inside its type-parameter scope.
+        while index.scope(enclosing_scope).kind() == ScopeKind::Annotation {
+            let Some(parent) = index.scope(enclosing_scope).parent() else {
+                return vec![];
+            };
+            enclosing_scope = parent;
+        }
+        let suite = match index.scope(enclosing_scope).node() {
+            NodeWithScopeKind::Module => &parsed_module(db.upcast(), file).syntax().body,
+            NodeWithScopeKind::Function(function) => &function.node().body,
+            _ => return vec![],
+        };
+        let name = &body_scope.node(db).expect_function().name;
+        let enclosing_scope_id = enclosing_scope.to_scope_id(db, file);
+
+        let mut registrations = vec![];
+        for function_stmt in suite.iter().filter_map(ast::Stmt::as_function_def_stmt) {
+            for decorator in &function_stmt.decorator_list {
+                let (callee, registered_class) = match &decorator.expression {
+                    ast::Expr::Call(call) if call.arguments.keywords.is_empty() => {
+                        match &*call.arguments.args {
+                            [argument] => (&*call.func, Some(argument)),
+                            _ => continue,
+                        }
+                    }
+                    expression => (expression, None),
+                };
+                let ast::Expr::Attribute(ast::ExprAttribute { value, attr, .. }) = callee else {
+                    continue;
+                };
+                if attr != "register"
+                    || !value
+                        .as_name_expr()
+                        .is_some_and(|target| target.id.as_str() == name.as_str())
+                {
+                    continue;
+                }
+                let Some(implementation) =
+                    binding_ty(db, index.definition(function_stmt)).into_function_literal()
+                else {
+                    continue;
+                };
+                let dispatch_ty = match registered_class {
+                    Some(argument) => infer_scope_types(db, enclosing_scope_id)
+                        .expression_ty(argument.scoped_ast_id(db, enclosing_scope_id))
+                        .to_instance(db),
+                    None => implementation
+                        .internal_signature(db)
+                        .positional_parameter_types()
+                        .first()
+                        .copied()
+                        .unwrap_or(Type::Unknown),
+                };
+                registrations.push((dispatch_ty, implementation));
+            }
+        }
+        registrations
+    }
+
+    /// The minimum and maximum number of arguments this function accepts, or `None` if the
+    /// counts cannot be read off its parameter list: `*args`/`**kwargs` and required
+    /// keyword-only parameters aren't modeled yet, decorators can change the signature
+    /// arbitrarily, and functions defined in a class body are usually accessed through an
+    /// instance (or through `super()`), which binds the first parameter implicitly.
+    fn positional_arity(self, db: &'db dyn Db) -> Option<(usize, usize)> {
+        let body_scope = self.body_scope(db);
+        let index = semantic_index(db, body_scope.file(db));
+        let mut enclosing_scope = body_scope.scope(db).parent()?;
+        // A generic function's body scope is nested inside its type-parameter scope.
+        while index.scope(enclosing_scope).kind() == ScopeKind::Annotation {
+            enclosing_scope = index.scope(enclosing_scope).parent()?;
+        }
+        if index.scope(enclosing_scope).kind() == ScopeKind::Class {
+            return None;
+        }
+        self.undecorated_positional_arity(db)
+    }
+
+    /// The raw argument counts read off this function's parameter list, including any
+    /// `self`/`cls` parameter. `None` for decorated functions and for parameter lists we
+    /// can't count (`*args`/`**kwargs`, required keyword-only parameters).
+    fn undecorated_positional_arity(self, db: &'db dyn Db) -> Option<(usize, usize)> {
+        let function_stmt_node = self.body_scope(db).node(db).expect_function();
+        if !function_stmt_node.decorator_list.is_empty() {
+            return None;
+        }
+        let parameters = &function_stmt_node.parameters;
+        if parameters.vararg.is_some() || parameters.kwarg.is_some() {
+            return None;
+        }
+        // Keyword-only parameters with defaults are optional; a keyword-only parameter
+        // without a default must be bound by a keyword argument, and we don't verify yet
+        // that every required keyword-only parameter receives one.
+        if parameters
+            .kwonlyargs
+            .iter()
+            .any(|parameter| parameter.default.is_none())
+        {
+            return None;
+        }
+        let positional = || parameters.posonlyargs.iter().chain(&parameters.args);
+        let required = positional()
+            .filter(|parameter| parameter.default.is_none())
+            .count();
+        Some((required, positional().count()))
+    }
+
     /// Typed externally-visible signature for this function.
     ///
     /// This is the signature as seen by external callers, possibly modified by decorators and/or
@@ -2355,17 +4443,72 @@ impl<'db> FunctionType<'db> {
     ///
     /// Were this not a salsa query, then the calling query
     /// would depend on the function's AST and rerun for every change in that file.
-    #[salsa::tracked(return_ref)]
+    #[salsa::tracked(return_ref, recovery_fn=signature_cycle_recovery)]
     pub fn signature(self, db: &'db dyn Db) -> Signature<'db> {
         let function_stmt_node = self.body_scope(db).node(db).expect_function();
         let internal_signature = self.internal_signature(db);
         if function_stmt_node.decorator_list.is_empty() {
-            return internal_signature;
+            return self.with_generator_return_ty(db, internal_signature);
+        }
+        // `classmethod` and `staticmethod` only affect how the first parameter is bound at
+        // access time; they don't affect the externally visible return type. A `property`
+        // getter's signature is likewise left intact: instance member lookup uses its return
+        // type directly. (Once we model parameters in `Signature`, the bound `cls` parameter
+        // of a classmethod will need to be dropped here.)
+        if self.decorators(db).len() == 1
+            && (self.is_classmethod(db) || self.is_staticmethod(db) || self.is_property(db))
+        {
+            return self.with_generator_return_ty(db, internal_signature);
+        }
+        // A `@singledispatch` wrapper callable keeps the base implementation's signature
+        // as the fallback; registered implementations are selected per call site.
+        if self.decorators(db).len() == 1 && self.is_singledispatch(db) {
+            return self.with_generator_return_ty(db, internal_signature);
         }
         // TODO process the effect of decorators on the signature
         Signature::todo()
     }
 
+    /// Typed signature of a single `@overload`-decorated definition of this function, or of
+    /// a `@f.register` implementation of a `@singledispatch` function.
+    ///
+    /// [`signature`](Self::signature) gives up on decorated functions; for overload
+    /// resolution and singledispatch selection the parameter and return types of each
+    /// definition are needed as written. Like `signature`, this is a salsa query so that
+    /// callers don't depend on the function's AST directly.
+    #[salsa::tracked(return_ref, recovery_fn=signature_cycle_recovery)]
+    fn overload_signature(self, db: &'db dyn Db) -> Signature<'db> {
+        self.internal_signature(db)
+    }
+
+    /// If this is a generator function without a return annotation, replace the return type of
+    /// `signature` with a `types.GeneratorType` instance yielding the union of all the types
+    /// yielded in the function body.
+    ///
+    /// TODO: model the send and return types as well (`Generator[Y, S, R]`), once we support
+    /// generics; for now only the yield type is tracked, which is what iteration over the
+    /// generator object produces.
+    fn with_generator_return_ty(
+        self,
+        db: &'db dyn Db,
+        mut signature: Signature<'db>,
+    ) -> Signature<'db> {
+        let function_stmt_node = self.body_scope(db).node(db).expect_function();
+        if function_stmt_node.returns.is_some() || function_stmt_node.is_async {
+            return signature;
+        }
+        let Some(yield_ty) = infer_generator_yield_ty(db, self) else {
+            return signature;
+        };
+        if let Some(ClassLiteralType { class }) = KnownClass::GeneratorType
+            .to_class_literal(db)
+            .into_class_literal()
+        {
+            signature.return_ty = Type::Iterator(IteratorType::new(db, class, yield_ty));
+        }
+        signature
+    }
+
     /// Typed internally-visible signature for this function.
     ///
     /// This represents the annotations on the function itself, unmodified by decorators and
@@ -2403,71 +4546,468 @@ pub enum KnownFunction {
     ConstraintFunction(KnownConstraintFunction),
     /// `builtins.reveal_type`, `typing.reveal_type` or `typing_extensions.reveal_type`
     RevealType,
+    /// `builtins.vars`
+    Vars,
+    /// `typing.get_type_hints` or `typing_extensions.get_type_hints`
+    GetTypeHints,
+    /// `dataclasses.replace`
+    Replace,
+    /// `typing.overload` or `typing_extensions.overload`
+    Overload,
+    /// `builtins.sorted`
+    Sorted,
+    /// `builtins.min`
+    Min,
+    /// `builtins.max`
+    Max,
+    /// `asyncio.gather`
+    Gather,
+    /// `builtins.hasattr`
+    HasAttr,
+    /// `functools.singledispatch`
+    SingleDispatch,
 }
 
 impl KnownFunction {
     pub fn constraint_function(self) -> Option<KnownConstraintFunction> {
         match self {
             Self::ConstraintFunction(f) => Some(f),
-            Self::RevealType => None,
+            _ => None,
+        }
+    }
+
+    fn from_definition<'db>(
+        db: &'db dyn Db,
+        definition: Definition<'db>,
+        name: &str,
+    ) -> Option<Self> {
+        match name {
+            "reveal_type" if definition.is_typing_definition(db) => Some(KnownFunction::RevealType),
+            "isinstance" if definition.is_builtin_definition(db) => Some(
+                KnownFunction::ConstraintFunction(KnownConstraintFunction::IsInstance),
+            ),
+            "issubclass" if definition.is_builtin_definition(db) => Some(
+                KnownFunction::ConstraintFunction(KnownConstraintFunction::IsSubclass),
+            ),
+            "vars" if definition.is_builtin_definition(db) => Some(KnownFunction::Vars),
+            "get_type_hints" if definition.is_typing_definition(db) => {
+                Some(KnownFunction::GetTypeHints)
+            }
+            "replace" if definition.is_dataclasses_definition(db) => Some(KnownFunction::Replace),
+            "overload" if definition.is_typing_definition(db) => Some(KnownFunction::Overload),
+            "sorted" if definition.is_builtin_definition(db) => Some(KnownFunction::Sorted),
+            "min" if definition.is_builtin_definition(db) => Some(KnownFunction::Min),
+            "max" if definition.is_builtin_definition(db) => Some(KnownFunction::Max),
+            "gather" if definition.is_asyncio_definition(db) => Some(KnownFunction::Gather),
+            "hasattr" if definition.is_builtin_definition(db) => Some(KnownFunction::HasAttr),
+            "singledispatch" if definition.is_functools_definition(db) => {
+                Some(KnownFunction::SingleDispatch)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Representation of a runtime class object.
+///
+/// Does not in itself represent a type,
+/// but is used as the inner data for several structs that *do* represent types.
+#[salsa::interned]
+pub struct Class<'db> {
+    /// Name of the class at definition
+    #[return_ref]
+    pub name: ast::name::Name,
+
+    body_scope: ScopeId<'db>,
+
+    known: Option<KnownClass>,
+
+    /// the message of a `@warnings.deprecated` decorator on this class, if any
+    #[return_ref]
+    deprecated: Option<Box<str>>,
+}
+
+#[salsa::tracked]
+impl<'db> Class<'db> {
+    /// Return `true` if this class represents `known_class`
+    pub fn is_known(self, db: &'db dyn Db, known_class: KnownClass) -> bool {
+        self.known(db) == Some(known_class)
+    }
+
+    /// Return an iterator over the inferred types of this class's *explicit* bases.
+    ///
+    /// Note that any class (except for `object`) that has no explicit
+    /// bases will implicitly inherit from `object` at runtime. Nonetheless,
+    /// this method does *not* include `object` in the bases it iterates over.
+    ///
+    /// ## Why is this a salsa query?
+    ///
+    /// This is a salsa query to short-circuit the invalidation
+    /// when the class's AST node changes.
+    ///
+    /// Were this not a salsa query, then the calling query
+    /// would depend on the class's AST and rerun for every change in that file.
+    fn explicit_bases(self, db: &'db dyn Db) -> &[Type<'db>] {
+        self.explicit_bases_query(db)
+    }
+
+    /// Is this class a protocol class, i.e. does it directly inherit from `typing.Protocol`?
+    pub(crate) fn is_protocol(self, db: &'db dyn Db) -> bool {
+        self.explicit_bases(db)
+            .iter()
+            .any(|base| matches!(base, Type::KnownInstance(KnownInstanceType::Protocol)))
+    }
+
+    /// Is this class a `TypedDict`, i.e. does it directly inherit from `typing.TypedDict`?
+    pub(crate) fn is_typed_dict(self, db: &'db dyn Db) -> bool {
+        self.explicit_bases(db)
+            .iter()
+            .any(|base| matches!(base, Type::KnownInstance(KnownInstanceType::TypedDict)))
+    }
+
+    /// The fields of a `TypedDict` class: the annotated assignments in the class body.
+    ///
+    /// All fields are currently treated as required.
+    /// TODO: honor `total=False` and `NotRequired`/`Required` qualifiers.
+    pub(crate) fn typed_dict_fields(self, db: &'db dyn Db) -> Vec<DataclassField<'db>> {
+        self.dataclass_fields(db)
+    }
+
+    /// Return `true` if this class is an enum, i.e. it inherits (directly or indirectly)
+    /// from `enum.Enum`.
+    pub(crate) fn is_enum(self, db: &'db dyn Db) -> bool {
+        self.iter_mro(db).any(|superclass| match superclass {
+            ClassBase::Class(class) => matches!(
+                class.known(db),
+                Some(
+                    KnownClass::Enum
+                        | KnownClass::IntEnum
+                        | KnownClass::StrEnum
+                        | KnownClass::Flag
+                        | KnownClass::IntFlag
+                )
+            ),
+            _ => false,
+        })
+    }
+
+    /// If this class's body defines a `@property` getter for `name`, return the getter
+    /// function.
+    pub(crate) fn property_getter(self, db: &'db dyn Db, name: &str) -> Option<FunctionType<'db>> {
+        let class_stmt_node = self.body_scope(db).node(db).expect_class();
+        class_stmt_node
+            .body
+            .iter()
+            .filter_map(ast::Stmt::as_function_def_stmt)
+            .filter(|function_stmt| function_stmt.name.as_str() == name)
+            .filter_map(|function_stmt| self.body_function_type(db, function_stmt))
+            .find(|function| function.is_property(db))
+    }
+
+    /// If this class's body defines a `@<name>.setter`-decorated setter for the property
+    /// `name`, return the setter function.
+    ///
+    /// The conventional `@x.setter` spelling is detected syntactically: the decorator
+    /// expression evaluates to a method of the `property` descriptor object, which we don't
+    /// model.
+    pub(crate) fn property_setter(self, db: &'db dyn Db, name: &str) -> Option<FunctionType<'db>> {
+        let class_stmt_node = self.body_scope(db).node(db).expect_class();
+        class_stmt_node
+            .body
+            .iter()
+            .filter_map(ast::Stmt::as_function_def_stmt)
+            .find(|function_stmt| {
+                function_stmt.name.as_str() == name
+                    && function_stmt.decorator_list.iter().any(|decorator| {
+                        matches!(
+                            &decorator.expression,
+                            ast::Expr::Attribute(ast::ExprAttribute { value, attr, .. })
+                                if attr == "setter"
+                                    && value
+                                        .as_name_expr()
+                                        .is_some_and(|target| target.id.as_str() == name)
+                        )
+                    })
+            })
+            .and_then(|function_stmt| self.body_function_type(db, function_stmt))
+    }
+
+    /// Resolve a `def` statement in this class's body to its inferred [`FunctionType`].
+    fn body_function_type(
+        self,
+        db: &'db dyn Db,
+        function_stmt: &ast::StmtFunctionDef,
+    ) -> Option<FunctionType<'db>> {
+        let index = semantic_index(db, self.body_scope(db).file(db));
+        binding_ty(db, index.definition(function_stmt)).into_function_literal()
+    }
+
+    /// The `@dataclass` decorator applied to this class, if any.
+    ///
+    /// Detected syntactically from the decorator list: `dataclass`,
+    /// `dataclasses.dataclass`, or a call of either.
+    fn dataclass_decorator(self, db: &'db dyn Db) -> Option<&'db ast::Decorator> {
+        fn is_dataclass_callee(expression: &ast::Expr) -> bool {
+            match expression {
+                ast::Expr::Name(name) => name.id == "dataclass",
+                ast::Expr::Attribute(attribute) => attribute.attr == "dataclass",
+                _ => false,
+            }
+        }
+        self.body_scope(db)
+            .node(db)
+            .expect_class()
+            .decorator_list
+            .iter()
+            .find(|decorator| match &decorator.expression {
+                ast::Expr::Call(call) => is_dataclass_callee(&call.func),
+                expression => is_dataclass_callee(expression),
+            })
+    }
+
+    /// Return `true` if this class is decorated with `@dataclass`.
+    pub(crate) fn is_dataclass(self, db: &'db dyn Db) -> bool {
+        self.dataclass_decorator(db).is_some()
+    }
+
+    /// Return `true` if this class is decorated with `@dataclass(frozen=True)`, making all
+    /// of its fields read-only.
+    pub(crate) fn is_frozen_dataclass(self, db: &'db dyn Db) -> bool {
+        let Some(decorator) = self.dataclass_decorator(db) else {
+            return false;
+        };
+        let ast::Expr::Call(call) = &decorator.expression else {
+            return false;
+        };
+        call.arguments.find_keyword("frozen").is_some_and(|keyword| {
+            matches!(
+                keyword.value,
+                ast::Expr::BooleanLiteral(ast::ExprBooleanLiteral { value: true, .. })
+            )
+        })
+    }
+
+    /// Return `true` if this class is decorated with `@functools.total_ordering`, which
+    /// fills in the missing ordering dunders at runtime from the one the class defines.
+    pub(crate) fn is_total_ordering(self, db: &'db dyn Db) -> bool {
+        fn is_total_ordering_callee(expression: &ast::Expr) -> bool {
+            match expression {
+                ast::Expr::Name(name) => name.id == "total_ordering",
+                ast::Expr::Attribute(attribute) => attribute.attr == "total_ordering",
+                _ => false,
+            }
+        }
+        self.body_scope(db)
+            .node(db)
+            .expect_class()
+            .decorator_list
+            .iter()
+            .any(|decorator| is_total_ordering_callee(&decorator.expression))
+    }
+
+    /// The fields of a `@dataclass`-decorated class: the annotated assignments in the class
+    /// body, in declaration order. These are the parameters of the generated `__init__`.
+    pub(crate) fn dataclass_fields(self, db: &'db dyn Db) -> Vec<DataclassField<'db>> {
+        // TODO: exclude `ClassVar` annotations, honor `init=False`, and treat `field()`
+        // calls without `default` or `default_factory` as not having a default.
+        self.body_scope(db)
+            .node(db)
+            .expect_class()
+            .body
+            .iter()
+            .filter_map(|stmt| {
+                let ast::Stmt::AnnAssign(ann_assign) = stmt else {
+                    return None;
+                };
+                let target = ann_assign.target.as_name_expr()?;
+                let ty = match self.own_class_member(db, &target.id) {
+                    Symbol::Type(ty, _) => ty,
+                    Symbol::Unbound => Type::Unknown,
+                };
+                Some(DataclassField {
+                    name: &target.id,
+                    ty,
+                    has_default: ann_assign.value.is_some(),
+                })
+            })
+            .collect()
+    }
+
+    /// How a construction call of this class resolves `__init__`.
+    ///
+    /// `object.__init__` is excluded from the lookup: almost every class inherits it, and
+    /// at runtime it only rejects arguments when `__new__` is not overridden, which is
+    /// handled separately by [`Class::overrides_dunder_new`].
+    pub(crate) fn init_method(self, db: &'db dyn Db) -> ClassInit<'db> {
+        for superclass in self.iter_mro(db) {
+            let ClassBase::Class(class) = superclass else {
+                return ClassInit::Unknown;
+            };
+            if class.is_known(db, KnownClass::Object) {
+                return ClassInit::Object;
+            }
+            match class.own_class_member(db, "__init__") {
+                Symbol::Unbound => {}
+                Symbol::Type(Type::FunctionLiteral(function), _) => {
+                    return ClassInit::Method(function);
+                }
+                Symbol::Type(..) => return ClassInit::Unknown,
+            }
+        }
+        ClassInit::Object
+    }
+
+    /// Is `__new__` overridden anywhere before `object` in the MRO?
+    ///
+    /// Dynamic bases conservatively count as overriding it.
+    pub(crate) fn overrides_dunder_new(self, db: &'db dyn Db) -> bool {
+        for superclass in self.iter_mro(db) {
+            let ClassBase::Class(class) = superclass else {
+                return true;
+            };
+            if class.is_known(db, KnownClass::Object) {
+                return false;
+            }
+            if !class.own_class_member(db, "__new__").is_unbound() {
+                return true;
+            }
         }
+        false
     }
 
-    fn from_definition<'db>(
-        db: &'db dyn Db,
-        definition: Definition<'db>,
-        name: &str,
-    ) -> Option<Self> {
-        match name {
-            "reveal_type" if definition.is_typing_definition(db) => Some(KnownFunction::RevealType),
-            "isinstance" if definition.is_builtin_definition(db) => Some(
-                KnownFunction::ConstraintFunction(KnownConstraintFunction::IsInstance),
-            ),
-            "issubclass" if definition.is_builtin_definition(db) => Some(
-                KnownFunction::ConstraintFunction(KnownConstraintFunction::IsSubclass),
-            ),
-            _ => None,
+    /// The annotated return type of this class's `__new__`, if `__new__` is overridden
+    /// before `object` in the MRO and carries a return annotation.
+    pub(crate) fn dunder_new_return_ty(self, db: &'db dyn Db) -> Option<Type<'db>> {
+        for superclass in self.iter_mro(db) {
+            let ClassBase::Class(class) = superclass else {
+                return None;
+            };
+            if class.is_known(db, KnownClass::Object) {
+                return None;
+            }
+            match class.own_class_member(db, "__new__") {
+                Symbol::Unbound => {}
+                Symbol::Type(Type::FunctionLiteral(function), _) => {
+                    let function_stmt_node = function.body_scope(db).node(db).expect_function();
+                    return function_stmt_node
+                        .returns
+                        .as_ref()
+                        .map(|_| function.signature(db).return_ty);
+                }
+                Symbol::Type(..) => return None,
+            }
         }
+        None
     }
-}
-
-/// Representation of a runtime class object.
-///
-/// Does not in itself represent a type,
-/// but is used as the inner data for several structs that *do* represent types.
-#[salsa::interned]
-pub struct Class<'db> {
-    /// Name of the class at definition
-    #[return_ref]
-    pub name: ast::name::Name,
 
-    body_scope: ScopeId<'db>,
-
-    known: Option<KnownClass>,
-}
-
-#[salsa::tracked]
-impl<'db> Class<'db> {
-    /// Return `true` if this class represents `known_class`
-    pub fn is_known(self, db: &'db dyn Db, known_class: KnownClass) -> bool {
-        self.known(db) == Some(known_class)
+    /// The `__call__` method defined by a custom metaclass of this class, if any.
+    ///
+    /// Only definitions earlier than `type` in the metaclass's MRO count: `type.__call__`
+    /// implements the default construction protocol, which is modeled separately.
+    fn metaclass_dunder_call(self, db: &'db dyn Db) -> Option<FunctionType<'db>> {
+        let ClassLiteralType { class: metaclass } = self.metaclass(db).into_class_literal()?;
+        for superclass in metaclass.iter_mro(db) {
+            let ClassBase::Class(class) = superclass else {
+                return None;
+            };
+            if class.is_known(db, KnownClass::Type) {
+                return None;
+            }
+            if let Symbol::Type(Type::FunctionLiteral(function), Boundness::Bound) =
+                class.own_class_member(db, "__call__")
+            {
+                return Some(function);
+            }
+        }
+        None
     }
 
-    /// Return an iterator over the inferred types of this class's *explicit* bases.
-    ///
-    /// Note that any class (except for `object`) that has no explicit
-    /// bases will implicitly inherit from `object` at runtime. Nonetheless,
-    /// this method does *not* include `object` in the bases it iterates over.
-    ///
-    /// ## Why is this a salsa query?
+    /// Return `true` if `other` structurally satisfies this protocol class.
     ///
-    /// This is a salsa query to short-circuit the invalidation
-    /// when the class's AST node changes.
+    /// For every member declared in this protocol's class body, `other` must have a member of
+    /// the same name with a compatible type.
+    pub(crate) fn is_protocol_satisfied_by(self, db: &'db dyn Db, other: Class<'db>) -> bool {
+        let table = symbol_table(db, self.body_scope(db));
+        table.symbols().all(|protocol_symbol| {
+            let name = protocol_symbol.name();
+            let Symbol::Type(protocol_member, _) = self.own_class_member(db, name) else {
+                // Names that are merely referenced in the protocol body (e.g. in
+                // annotations) are not members of the protocol.
+                return true;
+            };
+            let Symbol::Type(member, _) = other.class_member(db, name) else {
+                return false;
+            };
+            match (member, protocol_member) {
+                // TODO: compare parameter and return types once `Signature` models
+                // parameters; for now any method satisfies a protocol method.
+                (Type::FunctionLiteral(_), Type::FunctionLiteral(_)) => true,
+                _ => member.is_assignable_to(db, protocol_member),
+            }
+        })
+    }
+
+    /// Return `true` if this protocol class is a callback protocol — `__call__` is its only
+    /// member — and `function`'s signature is compatible with the declared `__call__`.
     ///
-    /// Were this not a salsa query, then the calling query
-    /// would depend on the class's AST and rerun for every change in that file.
-    fn explicit_bases(self, db: &'db dyn Db) -> &[Type<'db>] {
-        self.explicit_bases_query(db)
+    /// Parameters are compared contravariantly and the return type covariantly; parameter
+    /// lists we can't count (decorators, `*args`/`**kwargs`) are accepted rather than
+    /// producing false positives.
+    fn callback_protocol_accepts(self, db: &'db dyn Db, function: FunctionType<'db>) -> bool {
+        let table = symbol_table(db, self.body_scope(db));
+        let mut has_dunder_call = false;
+        for protocol_symbol in table.symbols() {
+            if self.own_class_member(db, protocol_symbol.name()).is_unbound() {
+                // Names that are merely referenced in the protocol body are not members.
+                continue;
+            }
+            if protocol_symbol.name() == "__call__" {
+                has_dunder_call = true;
+            } else {
+                return false;
+            }
+        }
+        if !has_dunder_call {
+            return false;
+        }
+        let Symbol::Type(Type::FunctionLiteral(dunder_call), Boundness::Bound) =
+            self.own_class_member(db, "__call__")
+        else {
+            return false;
+        };
+        let Some((call_required, call_maximum)) = dunder_call.undecorated_positional_arity(db)
+        else {
+            return true;
+        };
+        let Some((required, maximum)) = function.undecorated_positional_arity(db) else {
+            return true;
+        };
+        // `__call__`'s first parameter is the protocol instance itself; the function must
+        // accept every argument count the protocol's callers may pass.
+        if required > call_required.saturating_sub(1) || maximum < call_maximum.saturating_sub(1)
+        {
+            return false;
+        }
+        let call_signature = dunder_call.signature(db);
+        let function_signature = function.signature(db);
+        let call_parameters = call_signature.positional_parameter_types();
+        let function_parameters = function_signature.positional_parameter_types();
+        for (call_parameter, parameter) in
+            call_parameters.iter().skip(1).zip(function_parameters.iter())
+        {
+            // TODO: `TypeVar` parameters (generic signatures) can't be checked yet.
+            if matches!(call_parameter, Type::KnownInstance(_))
+                || matches!(parameter, Type::KnownInstance(_))
+            {
+                continue;
+            }
+            if !call_parameter.is_assignable_to(db, *parameter) {
+                return false;
+            }
+        }
+        function_signature
+            .return_ty
+            .is_assignable_to(db, call_signature.return_ty)
     }
 
     /// Iterate over this class's explicit bases, filtering out any bases that are not class objects.
@@ -2531,6 +5071,30 @@ impl<'db> Class<'db> {
         MroIterator::new(db, self)
     }
 
+    /// The resolved [method resolution order] ("MRO") of this class as an ordered list of
+    /// types, for consumers outside of type inference (documentation tooling, "show base
+    /// classes", diagnostics wanting to say "inherited from X").
+    ///
+    /// In the error case (invalid, duplicate or cyclic bases, or an unresolvable C3
+    /// linearization; a union base currently counts as an invalid base) the list holds the
+    /// fallback MRO `[<class>, Unknown, object]` that inference uses instead, so it is
+    /// always ordered and non-empty.
+    ///
+    /// [method resolution order]: https://docs.python.org/3/glossary.html#term-method-resolution-order
+    pub fn mro(self, db: &'db dyn Db) -> MroResolution<'db> {
+        match self.try_mro(db) {
+            Ok(mro) => MroResolution::Resolved(mro.iter().copied().map(Type::from).collect()),
+            Err(error) => MroResolution::Error(
+                error
+                    .fallback_mro()
+                    .iter()
+                    .copied()
+                    .map(Type::from)
+                    .collect(),
+            ),
+        }
+    }
+
     /// Return `true` if `other` is present in this class's MRO.
     pub fn is_subclass_of(self, db: &'db dyn Db, other: Class) -> bool {
         // `is_subclass_of` is checking the subtype relation, in which gradual types do not
@@ -2645,6 +5209,35 @@ impl<'db> Class<'db> {
             return self.metaclass(db).into();
         }
 
+        // The set of subclasses existing at runtime isn't statically knowable, but every
+        // element of the returned list is a subclass of this class, so synthesize
+        // `__subclasses__` as a method with return type `list[type[C]]`.
+        if name == "__subclasses__" {
+            if let Some(ClassLiteralType { class: list_class }) =
+                KnownClass::List.to_class_literal(db).into_class_literal()
+            {
+                let return_ty = Type::GenericInstance(GenericInstanceType::new(
+                    db,
+                    list_class,
+                    Box::from([Type::subclass_of(self)]),
+                ));
+                return Type::Callable(CallableType::new(db, Some(Box::from([])), return_ty, None))
+                    .into();
+            }
+        }
+
+        // Class-body assignments in an enum class define enum members: accessing one on the
+        // class produces an instance of the enum class, not the assigned value. Underscored
+        // names and methods never become members. The known `enum` base classes themselves
+        // are excluded: only their subclasses have members.
+        if self.known(db).is_none() && self.is_enum(db) {
+            if let Symbol::Type(ty, boundness) = self.own_class_member(db, name) {
+                if !name.starts_with('_') && !matches!(ty, Type::FunctionLiteral(_)) {
+                    return Symbol::Type(Type::instance(self), boundness);
+                }
+            }
+        }
+
         for superclass in self.iter_mro(db) {
             match superclass {
                 // TODO we may instead want to record the fact that we encountered dynamic, and intersect it with
@@ -2674,6 +5267,30 @@ impl<'db> Class<'db> {
         symbol(db, scope, name)
     }
 
+    /// Return `true` if instances of this class may have attributes beyond the symbols
+    /// found in the class bodies along its MRO.
+    ///
+    /// Methods can create implicit instance attributes by assigning to `self`
+    /// (`self.x = ...`); we don't discover those assignments yet, so any class with a
+    /// method might provide additional attributes. Classes defined in stub files are
+    /// exempt, since a stub declares all of its attributes in the class body. A dynamic
+    /// element in the MRO can provide any attribute at all.
+    fn may_define_implicit_attributes(self, db: &'db dyn Db) -> bool {
+        self.iter_mro(db).any(|superclass| match superclass {
+            ClassBase::Any | ClassBase::Unknown | ClassBase::Todo => true,
+            ClassBase::Class(class) => {
+                !class.body_scope(db).file(db).is_stub(db.upcast())
+                    && class
+                        .body_scope(db)
+                        .node(db)
+                        .expect_class()
+                        .body
+                        .iter()
+                        .any(ast::Stmt::is_function_def_stmt)
+            }
+        })
+    }
+
     /// Return `true` if this class appears to be a cyclic definition,
     /// i.e., it inherits either directly or indirectly from itself.
     ///
@@ -2708,6 +5325,25 @@ impl<'db> Class<'db> {
     }
 }
 
+/// The result of resolving a class's method resolution order via [`Class::mro`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MroResolution<'db> {
+    /// The MRO resolved successfully; the common case.
+    Resolved(Vec<Type<'db>>),
+    /// The MRO could not be accurately resolved; the contained list is the fallback MRO
+    /// that inference uses instead.
+    Error(Vec<Type<'db>>),
+}
+
+impl<'db> MroResolution<'db> {
+    /// The resolved or fallback MRO, in order.
+    pub fn types(&self) -> &[Type<'db>] {
+        match self {
+            Self::Resolved(types) | Self::Error(types) => types,
+        }
+    }
+}
+
 /// Either the explicit `metaclass=` keyword of the class, or the inferred metaclass of one of its base classes.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub(super) struct MetaclassCandidate<'db> {
@@ -2715,6 +5351,32 @@ pub(super) struct MetaclassCandidate<'db> {
     explicit_metaclass_of: Class<'db>,
 }
 
+/// A field of a `@dataclass`-decorated class, derived from an annotated assignment in the
+/// class body; see [`Class::dataclass_fields`].
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct DataclassField<'db> {
+    /// Name of the field
+    pub(crate) name: &'db ast::name::Name,
+    /// The field's declared type, which is also the type of the corresponding `__init__`
+    /// parameter
+    pub(crate) ty: Type<'db>,
+    /// Whether the field's declaration has a default value, making the parameter optional
+    pub(crate) has_default: bool,
+}
+
+/// The `__init__` implementation a construction call of a class dispatches to; see
+/// [`Class::init_method`].
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum ClassInit<'db> {
+    /// `__init__` is defined before `object` in the MRO.
+    Method(FunctionType<'db>),
+    /// Only `object.__init__` applies.
+    Object,
+    /// The applicable `__init__` can't be resolved: the MRO contains a dynamic base, or
+    /// `__init__` is not a plain function.
+    Unknown,
+}
+
 /// A singleton type representing a single class object at runtime.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, salsa::Update)]
 pub struct ClassLiteralType<'db> {
@@ -2878,6 +5540,127 @@ impl<'db> SliceLiteralType<'db> {
         (self.start(db), self.stop(db), self.step(db))
     }
 }
+/// An instance of a generic class for which we know the type arguments it was
+/// specialized with, e.g. `list[int]` or `dict[str, int]`.
+#[salsa::interned]
+pub struct GenericInstanceType<'db> {
+    /// The runtime class of the instance
+    class: Class<'db>,
+    /// The type arguments the class was specialized with, in declaration order
+    #[return_ref]
+    specialization: Box<[Type<'db>]>,
+}
+
+impl<'db> GenericInstanceType<'db> {
+    /// The type of the values yielded by iterating over this instance, if its class is
+    /// one of the builtin containers whose element types we track.
+    ///
+    /// Iterating over a `dict` yields its keys.
+    fn iteration_element_ty(self, db: &'db dyn Db) -> Option<Type<'db>> {
+        match self.class(db).known(db)? {
+            KnownClass::List | KnownClass::Set | KnownClass::FrozenSet | KnownClass::Dict => {
+                self.specialization(db).first().copied()
+            }
+            _ => None,
+        }
+    }
+}
+
+/// An instance of an iterator class (e.g. the object returned by a call to `zip()`)
+/// for which we know the type of the values yielded by each iteration step,
+/// even though we do not support generics yet.
+#[salsa::interned]
+pub struct IteratorType<'db> {
+    /// The runtime class of the iterator object
+    class: Class<'db>,
+    /// The type of the values yielded by each call to `__next__`
+    yields: Type<'db>,
+}
+
+/// An instance of a coroutine class (the object returned by calling an `async` function)
+/// for which we know the type of the value it produces when awaited,
+/// even though we do not support generics yet.
+#[salsa::interned]
+pub struct CoroutineType<'db> {
+    /// The runtime class of the coroutine object
+    class: Class<'db>,
+    /// The type of the value produced by awaiting the coroutine
+    result: Type<'db>,
+}
+
+/// A callable type, as written with `Callable[[int], str]`: we know the parameter types and the
+/// return type of the callable objects in the type, but nothing else about them.
+#[salsa::interned]
+pub struct CallableType<'db> {
+    /// Types of the parameters, all of which are treated as positional-only.
+    /// `None` is the gradual parameter list written `Callable[..., T]`,
+    /// which is consistent with any parameter list.
+    #[return_ref]
+    parameter_types: Option<Box<[Type<'db>]>>,
+
+    /// Return type of the callable
+    return_ty: Type<'db>,
+
+    /// The `ParamSpec` this callable's parameter list is bound to, if the annotation was
+    /// written `Callable[P, T]`; `parameter_types` is `None` in that case.
+    param_spec: Option<ParamSpecInstance<'db>>,
+}
+
+/// Which of the two type-guard special forms a [`TypeGuardType`] was written with.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum TypeGuardKind {
+    /// `TypeGuard[T]`: a `True` return narrows the guarded argument to `T`; a `False`
+    /// return tells us nothing, since a guard may reject values that do have type `T`.
+    TypeGuard,
+    /// `TypeIs[T]`: a `True` return narrows the guarded argument to `T`, and a `False`
+    /// return narrows it to "not `T`".
+    TypeIs,
+}
+
+/// The declared return type of a type-guard function, written as `TypeGuard[T]` or `TypeIs[T]`:
+/// a `bool` that, when the call is used as a branch condition, narrows the type of the
+/// function's first argument.
+#[salsa::interned]
+pub struct TypeGuardType<'db> {
+    /// Whether this guard was written with `TypeGuard` or `TypeIs`
+    kind: TypeGuardKind,
+
+    /// The type the guarded argument is narrowed to
+    guarded_ty: Type<'db>,
+}
+
+/// An instance of `builtins.super`: a proxy that delegates attribute lookup to the classes
+/// following `pivot_class` in its MRO.
+///
+/// Both the zero-argument form (where the pivot class is the class enclosing the call) and
+/// the two-argument form `super(C, obj)` are represented by this type. We don't record the
+/// bound object/class, so lookup starts after the pivot class in the pivot class's own MRO.
+#[salsa::interned]
+pub struct BoundSuperType<'db> {
+    /// The class after which the MRO search for attributes starts
+    pivot_class: Class<'db>,
+}
+
+impl<'db> BoundSuperType<'db> {
+    /// Look up `name` on the classes following the pivot class in the MRO.
+    fn member(self, db: &'db dyn Db, name: &str) -> Symbol<'db> {
+        for superclass in self.pivot_class(db).iter_mro(db).skip(1) {
+            match superclass {
+                ClassBase::Any | ClassBase::Unknown | ClassBase::Todo => {
+                    return Type::from(superclass).member(db, name)
+                }
+                ClassBase::Class(class) => {
+                    let member = class.own_class_member(db, name);
+                    if !member.is_unbound() {
+                        return member;
+                    }
+                }
+            }
+        }
+        Symbol::Unbound
+    }
+}
+
 #[salsa::interned]
 pub struct TupleType<'db> {
     #[return_ref]
@@ -2894,6 +5677,59 @@ impl<'db> TupleType<'db> {
     }
 }
 
+/// Cached structural size of a union type; see [`Type::size`].
+#[salsa::tracked]
+fn union_size<'db>(db: &'db dyn Db, union: UnionType<'db>) -> usize {
+    1 + union
+        .elements(db)
+        .iter()
+        .map(|element| element.size(db))
+        .sum::<usize>()
+}
+
+/// Cached structural size of an intersection type; see [`Type::size`].
+#[salsa::tracked]
+fn intersection_size<'db>(db: &'db dyn Db, intersection: IntersectionType<'db>) -> usize {
+    1 + intersection
+        .positive(db)
+        .iter()
+        .chain(intersection.negative(db))
+        .map(|element| element.size(db))
+        .sum::<usize>()
+}
+
+/// Cached structural size of a generic instance type; see [`Type::size`].
+#[salsa::tracked]
+fn generic_instance_size<'db>(db: &'db dyn Db, generic: GenericInstanceType<'db>) -> usize {
+    1 + generic
+        .specialization(db)
+        .iter()
+        .map(|argument| argument.size(db))
+        .sum::<usize>()
+}
+
+/// Cached structural size of a tuple type; see [`Type::size`].
+#[salsa::tracked]
+fn tuple_size<'db>(db: &'db dyn Db, tuple: TupleType<'db>) -> usize {
+    1 + tuple
+        .elements(db)
+        .iter()
+        .map(|element| element.size(db))
+        .sum::<usize>()
+}
+
+/// Cached structural size of a callable type; see [`Type::size`].
+#[salsa::tracked]
+fn callable_size<'db>(db: &'db dyn Db, callable: CallableType<'db>) -> usize {
+    1 + callable
+        .parameter_types(db)
+        .iter()
+        .flatten()
+        .map(|parameter| parameter.size(db))
+        .sum::<usize>()
+        + callable.return_ty(db).size(db)
+}
+
 #[cfg(test)]
 pub(crate) mod tests {
     use super::*;
@@ -2928,6 +5764,7 @@ pub(crate) mod tests {
             &db,
             &ProgramSettings {
                 target_version: PythonVersion::default(),
+                respect_noqa: false,
                 search_paths: SearchPathSettings::new(src_root),
             },
         )
@@ -2956,10 +5793,13 @@ pub(crate) mod tests {
         TypingLiteral,
         // BuiltinClassLiteral("str") corresponds to the builtin `str` class object itself
         BuiltinClassLiteral(&'static str),
+        // BuiltinFunctionLiteral("chr") corresponds to the builtin `chr` function object itself
+        BuiltinFunctionLiteral(&'static str),
         KnownClassInstance(KnownClass),
         Union(Vec<Ty>),
         Intersection { pos: Vec<Ty>, neg: Vec<Ty> },
         Tuple(Vec<Ty>),
+        Callable { params: Vec<Ty>, returns: Box<Ty> },
     }
 
     impl Ty {
@@ -2979,6 +5819,7 @@ pub(crate) mod tests {
                 Ty::TypingInstance(s) => typing_symbol(db, s).expect_type().to_instance(db),
                 Ty::TypingLiteral => Type::KnownInstance(KnownInstanceType::Literal),
                 Ty::BuiltinClassLiteral(s) => builtins_symbol(db, s).expect_type(),
+                Ty::BuiltinFunctionLiteral(s) => builtins_symbol(db, s).expect_type(),
                 Ty::KnownClassInstance(known_class) => known_class.to_instance(db),
                 Ty::Union(tys) => {
                     UnionType::from_elements(db, tys.into_iter().map(|ty| ty.into_type(db)))
@@ -2997,6 +5838,16 @@ pub(crate) mod tests {
                     let elements: Vec<Type> = tys.into_iter().map(|ty| ty.into_type(db)).collect();
                     Type::tuple(db, &elements)
                 }
+                Ty::Callable { params, returns } => {
+                    let parameter_types: Box<[Type]> =
+                        params.into_iter().map(|ty| ty.into_type(db)).collect();
+                    Type::Callable(CallableType::new(
+                        db,
+                        Some(parameter_types),
+                        returns.into_type(db),
+                        None,
+                    ))
+                }
             }
         }
     }
@@ -3026,6 +5877,18 @@ pub(crate) mod tests {
     )]
     #[test_case(Ty::Tuple(vec![Ty::Todo]), Ty::Tuple(vec![Ty::IntLiteral(2)]))]
     #[test_case(Ty::Tuple(vec![Ty::IntLiteral(2)]), Ty::Tuple(vec![Ty::Todo]))]
+    #[test_case(
+        Ty::Callable { params: vec![Ty::Unknown], returns: Box::new(Ty::Unknown) },
+        Ty::Callable { params: vec![Ty::BuiltinInstance("int")], returns: Box::new(Ty::BuiltinInstance("object")) }
+    )]
+    #[test_case(
+        Ty::BuiltinFunctionLiteral("chr"),
+        Ty::Callable { params: vec![Ty::BuiltinInstance("int")], returns: Box::new(Ty::BuiltinInstance("str")) }
+    )]
+    #[test_case(
+        Ty::BuiltinFunctionLiteral("chr"),
+        Ty::Callable { params: vec![Ty::BuiltinInstance("bool")], returns: Box::new(Ty::BuiltinInstance("object")) }
+    )]
     fn is_assignable_to(from: Ty, to: Ty) {
         let db = setup_db();
         assert!(from.into_type(&db).is_assignable_to(&db, to.into_type(&db)));
@@ -3043,6 +5906,14 @@ pub(crate) mod tests {
         Ty::Union(vec![Ty::IntLiteral(1), Ty::None]),
         Ty::Union(vec![Ty::BuiltinInstance("str"), Ty::None])
     )]
+    #[test_case(
+        Ty::BuiltinFunctionLiteral("chr"),
+        Ty::Callable { params: vec![Ty::BuiltinInstance("object")], returns: Box::new(Ty::BuiltinInstance("str")) }
+    )]
+    #[test_case(
+        Ty::BuiltinFunctionLiteral("chr"),
+        Ty::Callable { params: vec![Ty::BuiltinInstance("int")], returns: Box::new(Ty::BuiltinInstance("int")) }
+    )]
     fn is_not_assignable_to(from: Ty, to: Ty) {
         let db = setup_db();
         assert!(!from.into_type(&db).is_assignable_to(&db, to.into_type(&db)));
@@ -3087,6 +5958,18 @@ pub(crate) mod tests {
     #[test_case(Ty::BuiltinClassLiteral("int"), Ty::BuiltinInstance("object"))]
     #[test_case(Ty::TypingLiteral, Ty::TypingInstance("_SpecialForm"))]
     #[test_case(Ty::TypingLiteral, Ty::BuiltinInstance("object"))]
+    #[test_case(
+        Ty::Callable { params: vec![Ty::BuiltinInstance("int")], returns: Box::new(Ty::BuiltinInstance("str")) },
+        Ty::Callable { params: vec![Ty::BuiltinInstance("int")], returns: Box::new(Ty::BuiltinInstance("object")) }
+    )]
+    #[test_case(
+        Ty::Callable { params: vec![Ty::BuiltinInstance("object")], returns: Box::new(Ty::BuiltinInstance("str")) },
+        Ty::Callable { params: vec![Ty::BuiltinInstance("int")], returns: Box::new(Ty::BuiltinInstance("str")) }
+    )]
+    #[test_case(
+        Ty::Callable { params: vec![], returns: Box::new(Ty::BuiltinInstance("bool")) },
+        Ty::Callable { params: vec![], returns: Box::new(Ty::BuiltinInstance("int")) }
+    )]
     fn is_subtype_of(from: Ty, to: Ty) {
         let db = setup_db();
         assert!(from.into_type(&db).is_subtype_of(&db, to.into_type(&db)));
@@ -3114,6 +5997,18 @@ pub(crate) mod tests {
     #[test_case(Ty::IntLiteral(1), Ty::Intersection{pos: vec![Ty::BuiltinInstance("int")], neg: vec![Ty::IntLiteral(1)]})]
     #[test_case(Ty::BuiltinClassLiteral("int"), Ty::BuiltinClassLiteral("object"))]
     #[test_case(Ty::BuiltinInstance("int"), Ty::BuiltinClassLiteral("int"))]
+    #[test_case(
+        Ty::Callable { params: vec![Ty::BuiltinInstance("int")], returns: Box::new(Ty::BuiltinInstance("object")) },
+        Ty::Callable { params: vec![Ty::BuiltinInstance("int")], returns: Box::new(Ty::BuiltinInstance("str")) }
+    )]
+    #[test_case(
+        Ty::Callable { params: vec![Ty::BuiltinInstance("int")], returns: Box::new(Ty::BuiltinInstance("str")) },
+        Ty::Callable { params: vec![Ty::BuiltinInstance("object")], returns: Box::new(Ty::BuiltinInstance("str")) }
+    )]
+    #[test_case(
+        Ty::Callable { params: vec![], returns: Box::new(Ty::BuiltinInstance("str")) },
+        Ty::Callable { params: vec![Ty::BuiltinInstance("int")], returns: Box::new(Ty::BuiltinInstance("str")) }
+    )]
     fn is_not_subtype_of(from: Ty, to: Ty) {
         let db = setup_db();
         assert!(!from.into_type(&db).is_subtype_of(&db, to.into_type(&db)));
@@ -3560,4 +6455,76 @@ pub(crate) mod tests {
 
         Ok(())
     }
+
+    #[test_case(Ty::BuiltinClassLiteral("str"), Callability::Callable)]
+    #[test_case(Ty::Any, Callability::UnknownSignature)]
+    #[test_case(Ty::Unknown, Callability::UnknownSignature)]
+    #[test_case(Ty::Todo, Callability::UnknownSignature)]
+    #[test_case(Ty::IntLiteral(3), Callability::NotCallable)]
+    #[test_case(Ty::StringLiteral("foo"), Callability::NotCallable)]
+    #[test_case(Ty::None, Callability::NotCallable)]
+    #[test_case(Ty::BuiltinInstance("int"), Callability::NotCallable)]
+    #[test_case(
+        Ty::Union(vec![Ty::BuiltinClassLiteral("str"), Ty::BuiltinClassLiteral("int")]),
+        Callability::Callable
+    )]
+    #[test_case(
+        Ty::Union(vec![Ty::BuiltinClassLiteral("str"), Ty::IntLiteral(3)]),
+        Callability::PossiblyNotCallable
+    )]
+    #[test_case(
+        Ty::Union(vec![Ty::IntLiteral(3), Ty::None]),
+        Callability::NotCallable
+    )]
+    fn callability_classification(ty: Ty, expected: Callability) {
+        let db = setup_db();
+
+        assert_eq!(ty.into_type(&db).callability(&db), expected);
+    }
+
+    #[test]
+    fn dunder_call_none_is_classified() {
+        let mut db = setup_db();
+        db.write_dedented(
+            "/src/module.py",
+            "
+            class NotCallable:
+                __call__ = None
+
+            instance = NotCallable()
+            ",
+        )
+        .unwrap();
+        let module = system_path_to_file(&db, "/src/module.py").unwrap();
+
+        let instance = super::global_symbol(&db, module, "instance").expect_type();
+        assert_eq!(instance.callability(&db), Callability::DunderCallIsNone);
+    }
+
+    #[test]
+    fn diagnostic_severity_depends_on_rule() {
+        use ruff_db::diagnostic::Severity;
+
+        let mut db = setup_db();
+        db.write_dedented(
+            "/src/module.py",
+            "
+            reveal_type(1)
+            3()
+            ",
+        )
+        .unwrap();
+        let module = system_path_to_file(&db, "/src/module.py").unwrap();
+
+        let diagnostics = check_types(&db, module);
+        let severity = |rule: &str| {
+            diagnostics
+                .iter()
+                .find(|diagnostic| diagnostic.rule() == rule)
+                .map(|diagnostic| diagnostic.severity())
+        };
+
+        assert_eq!(severity("revealed-type"), Some(Severity::Info));
+        assert_eq!(severity("call-non-callable"), Some(Severity::Error));
+    }
 }
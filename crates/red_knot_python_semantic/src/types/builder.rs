@@ -397,6 +397,7 @@ mod tests {
             &db,
             &ProgramSettings {
                 target_version: PythonVersion::default(),
+                respect_noqa: false,
                 search_paths: SearchPathSettings::new(src_root),
             },
         )
@@ -1,4 +1,5 @@
-use crate::types::{ClassLiteralType, Type};
+use crate::semantic_index::UnboundReason;
+use crate::types::{ClassLiteralType, NotAsyncIterableReason, NotIterableReason, Type};
 use crate::Db;
 use ruff_db::diagnostic::{Diagnostic, Severity};
 use ruff_db::files::File;
@@ -11,16 +12,16 @@ use std::sync::Arc;
 
 #[derive(Debug, Eq, PartialEq, Clone)]
 pub struct TypeCheckDiagnostic {
-    // TODO: Don't use string keys for rules
-    pub(super) rule: String,
+    pub(super) rule: Rule,
     pub(super) message: String,
     pub(super) range: TextRange,
     pub(super) file: File,
+    pub(super) severity: Severity,
 }
 
 impl TypeCheckDiagnostic {
     pub fn rule(&self) -> &str {
-        &self.rule
+        self.rule.as_str()
     }
 
     pub fn message(&self) -> &str {
@@ -30,6 +31,10 @@ impl TypeCheckDiagnostic {
     pub fn file(&self) -> File {
         self.file
     }
+
+    pub fn severity(&self) -> Severity {
+        self.severity
+    }
 }
 
 impl Diagnostic for TypeCheckDiagnostic {
@@ -50,7 +55,227 @@ impl Diagnostic for TypeCheckDiagnostic {
     }
 
     fn severity(&self) -> Severity {
-        Severity::Error
+        TypeCheckDiagnostic::severity(self)
+    }
+}
+
+/// A rule that a [`TypeCheckDiagnostic`] can be reported under.
+///
+/// Each rule has a stable kebab-case name, which is what appears in output and in
+/// `knot: ignore[...]` suppression comments, and a default [`Severity`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub(crate) enum Rule {
+    AnnotationByteString,
+    AnnotationEscapeCharacter,
+    AnnotationFString,
+    AnnotationImplicitConcat,
+    AnnotationRawString,
+    AwaitOutsideAsync,
+    CallNonCallable,
+    CallPossiblyUnboundMethod,
+    ConflictingDeclarations,
+    ConflictingMetaclass,
+    CyclicClassDef,
+    Deprecated,
+    DivisionByZero,
+    DuplicateBase,
+    ForwardAnnotationSyntaxError,
+    InconsistentMro,
+    IndexOutOfBounds,
+    InvalidAll,
+    InvalidArgumentType,
+    InvalidArguments,
+    InvalidAssignment,
+    InvalidAttributeAssignment,
+    InvalidAwait,
+    InvalidBase,
+    InvalidCallableParameter,
+    InvalidContextManager,
+    InvalidDeclaration,
+    InvalidLiteralParameter,
+    InvalidTypeParameter,
+    InvalidTypevarConstraints,
+    NoMatchingOverload,
+    NonSubscriptable,
+    NotAsyncIterable,
+    NotIterable,
+    PossiblyNoneAttribute,
+    PossiblyUnboundAttribute,
+    PossiblyUnboundImport,
+    PossiblyUnresolvedReference,
+    ReturnOutsideFunction,
+    ReturnTypeMismatch,
+    RevealedType,
+    TooFewArguments,
+    TooManyArguments,
+    TypeTooComplex,
+    UndefinedExport,
+    UndefinedReveal,
+    UnionSyntaxNotAvailable,
+    UnknownRule,
+    UnorderableType,
+    UnreachableExcept,
+    UnresolvedAttribute,
+    UnresolvedImport,
+    UnresolvedReference,
+    UnsupportedFormat,
+    UnsupportedOperator,
+    UnusedIgnore,
+    YieldOutsideFunction,
+    ZeroStepsizeInSlice,
+}
+
+impl Rule {
+    /// The rule's stable kebab-case name.
+    pub(crate) const fn as_str(self) -> &'static str {
+        match self {
+            Rule::AnnotationByteString => "annotation-byte-string",
+            Rule::AnnotationEscapeCharacter => "annotation-escape-character",
+            Rule::AnnotationFString => "annotation-f-string",
+            Rule::AnnotationImplicitConcat => "annotation-implicit-concat",
+            Rule::AnnotationRawString => "annotation-raw-string",
+            Rule::AwaitOutsideAsync => "await-outside-async",
+            Rule::CallNonCallable => "call-non-callable",
+            Rule::CallPossiblyUnboundMethod => "call-possibly-unbound-method",
+            Rule::ConflictingDeclarations => "conflicting-declarations",
+            Rule::ConflictingMetaclass => "conflicting-metaclass",
+            Rule::CyclicClassDef => "cyclic-class-def",
+            Rule::Deprecated => "deprecated",
+            Rule::DivisionByZero => "division-by-zero",
+            Rule::DuplicateBase => "duplicate-base",
+            Rule::ForwardAnnotationSyntaxError => "forward-annotation-syntax-error",
+            Rule::InconsistentMro => "inconsistent-mro",
+            Rule::IndexOutOfBounds => "index-out-of-bounds",
+            Rule::InvalidAll => "invalid-all",
+            Rule::InvalidArgumentType => "invalid-argument-type",
+            Rule::InvalidArguments => "invalid-arguments",
+            Rule::InvalidAssignment => "invalid-assignment",
+            Rule::InvalidAttributeAssignment => "invalid-attribute-assignment",
+            Rule::InvalidAwait => "invalid-await",
+            Rule::InvalidBase => "invalid-base",
+            Rule::InvalidCallableParameter => "invalid-callable-parameter",
+            Rule::InvalidContextManager => "invalid-context-manager",
+            Rule::InvalidDeclaration => "invalid-declaration",
+            Rule::InvalidLiteralParameter => "invalid-literal-parameter",
+            Rule::InvalidTypeParameter => "invalid-type-parameter",
+            Rule::InvalidTypevarConstraints => "invalid-typevar-constraints",
+            Rule::NoMatchingOverload => "no-matching-overload",
+            Rule::NonSubscriptable => "non-subscriptable",
+            Rule::NotAsyncIterable => "not-async-iterable",
+            Rule::NotIterable => "not-iterable",
+            Rule::PossiblyNoneAttribute => "possibly-none-attribute",
+            Rule::PossiblyUnboundAttribute => "possibly-unbound-attribute",
+            Rule::PossiblyUnboundImport => "possibly-unbound-import",
+            Rule::PossiblyUnresolvedReference => "possibly-unresolved-reference",
+            Rule::ReturnOutsideFunction => "return-outside-function",
+            Rule::ReturnTypeMismatch => "return-type-mismatch",
+            Rule::RevealedType => "revealed-type",
+            Rule::TooFewArguments => "too-few-arguments",
+            Rule::TooManyArguments => "too-many-arguments",
+            Rule::TypeTooComplex => "type-too-complex",
+            Rule::UndefinedExport => "undefined-export",
+            Rule::UndefinedReveal => "undefined-reveal",
+            Rule::UnionSyntaxNotAvailable => "union-syntax-not-available",
+            Rule::UnknownRule => "unknown-rule",
+            Rule::UnorderableType => "unorderable-type",
+            Rule::UnreachableExcept => "unreachable-except",
+            Rule::UnresolvedAttribute => "unresolved-attribute",
+            Rule::UnresolvedImport => "unresolved-import",
+            Rule::UnresolvedReference => "unresolved-reference",
+            Rule::UnsupportedFormat => "unsupported-format",
+            Rule::UnsupportedOperator => "unsupported-operator",
+            Rule::UnusedIgnore => "unused-ignore",
+            Rule::YieldOutsideFunction => "yield-outside-function",
+            Rule::ZeroStepsizeInSlice => "zero-stepsize-in-slice",
+        }
+    }
+
+    /// The inverse of [`Rule::as_str`]: resolves a rule from the name used in a
+    /// suppression comment, or `None` if no rule has that name.
+    pub(crate) fn from_name(name: &str) -> Option<Rule> {
+        Some(match name {
+            "annotation-byte-string" => Rule::AnnotationByteString,
+            "annotation-escape-character" => Rule::AnnotationEscapeCharacter,
+            "annotation-f-string" => Rule::AnnotationFString,
+            "annotation-implicit-concat" => Rule::AnnotationImplicitConcat,
+            "annotation-raw-string" => Rule::AnnotationRawString,
+            "await-outside-async" => Rule::AwaitOutsideAsync,
+            "call-non-callable" => Rule::CallNonCallable,
+            "call-possibly-unbound-method" => Rule::CallPossiblyUnboundMethod,
+            "conflicting-declarations" => Rule::ConflictingDeclarations,
+            "conflicting-metaclass" => Rule::ConflictingMetaclass,
+            "cyclic-class-def" => Rule::CyclicClassDef,
+            "deprecated" => Rule::Deprecated,
+            "division-by-zero" => Rule::DivisionByZero,
+            "duplicate-base" => Rule::DuplicateBase,
+            "forward-annotation-syntax-error" => Rule::ForwardAnnotationSyntaxError,
+            "inconsistent-mro" => Rule::InconsistentMro,
+            "index-out-of-bounds" => Rule::IndexOutOfBounds,
+            "invalid-all" => Rule::InvalidAll,
+            "invalid-argument-type" => Rule::InvalidArgumentType,
+            "invalid-arguments" => Rule::InvalidArguments,
+            "invalid-assignment" => Rule::InvalidAssignment,
+            "invalid-attribute-assignment" => Rule::InvalidAttributeAssignment,
+            "invalid-await" => Rule::InvalidAwait,
+            "invalid-base" => Rule::InvalidBase,
+            "invalid-callable-parameter" => Rule::InvalidCallableParameter,
+            "invalid-context-manager" => Rule::InvalidContextManager,
+            "invalid-declaration" => Rule::InvalidDeclaration,
+            "invalid-literal-parameter" => Rule::InvalidLiteralParameter,
+            "invalid-type-parameter" => Rule::InvalidTypeParameter,
+            "invalid-typevar-constraints" => Rule::InvalidTypevarConstraints,
+            "no-matching-overload" => Rule::NoMatchingOverload,
+            "non-subscriptable" => Rule::NonSubscriptable,
+            "not-async-iterable" => Rule::NotAsyncIterable,
+            "not-iterable" => Rule::NotIterable,
+            "possibly-none-attribute" => Rule::PossiblyNoneAttribute,
+            "possibly-unbound-attribute" => Rule::PossiblyUnboundAttribute,
+            "possibly-unbound-import" => Rule::PossiblyUnboundImport,
+            "possibly-unresolved-reference" => Rule::PossiblyUnresolvedReference,
+            "return-outside-function" => Rule::ReturnOutsideFunction,
+            "return-type-mismatch" => Rule::ReturnTypeMismatch,
+            "revealed-type" => Rule::RevealedType,
+            "too-few-arguments" => Rule::TooFewArguments,
+            "too-many-arguments" => Rule::TooManyArguments,
+            "type-too-complex" => Rule::TypeTooComplex,
+            "undefined-export" => Rule::UndefinedExport,
+            "undefined-reveal" => Rule::UndefinedReveal,
+            "union-syntax-not-available" => Rule::UnionSyntaxNotAvailable,
+            "unknown-rule" => Rule::UnknownRule,
+            "unorderable-type" => Rule::UnorderableType,
+            "unreachable-except" => Rule::UnreachableExcept,
+            "unresolved-attribute" => Rule::UnresolvedAttribute,
+            "unresolved-import" => Rule::UnresolvedImport,
+            "unresolved-reference" => Rule::UnresolvedReference,
+            "unsupported-format" => Rule::UnsupportedFormat,
+            "unsupported-operator" => Rule::UnsupportedOperator,
+            "unused-ignore" => Rule::UnusedIgnore,
+            "yield-outside-function" => Rule::YieldOutsideFunction,
+            "zero-stepsize-in-slice" => Rule::ZeroStepsizeInSlice,
+            _ => return None,
+        })
+    }
+
+    /// The severity a diagnostic reported under this rule has by default.
+    ///
+    /// Most rules report genuine type errors; `revealed-type` is informational output the user
+    /// asked for, and the "possibly"-style rules flag code that may well be fine at runtime.
+    /// In particular, a reference that is unresolved on every control flow path is an error,
+    /// while one that is only unresolved on some paths is a warning. Using deprecated code
+    /// still works at runtime, so that is a warning too.
+    pub(crate) const fn default_severity(self) -> Severity {
+        match self {
+            Rule::RevealedType => Severity::Info,
+            Rule::CallPossiblyUnboundMethod
+            | Rule::Deprecated
+            | Rule::PossiblyNoneAttribute
+            | Rule::PossiblyUnboundAttribute
+            | Rule::PossiblyUnboundImport
+            | Rule::PossiblyUnresolvedReference
+            | Rule::UnknownRule
+            | Rule::UnusedIgnore => Severity::Warning,
+            _ => Severity::Error,
+        }
     }
 }
 
@@ -80,6 +305,10 @@ impl TypeCheckDiagnostics {
     pub(crate) fn shrink_to_fit(&mut self) {
         self.inner.shrink_to_fit();
     }
+
+    pub(super) fn retain(&mut self, mut f: impl FnMut(&TypeCheckDiagnostic) -> bool) {
+        self.inner.retain(|diagnostic| f(diagnostic));
+    }
 }
 
 impl Extend<TypeCheckDiagnostic> for TypeCheckDiagnostics {
@@ -148,13 +377,68 @@ impl<'db> TypeCheckDiagnosticsBuilder<'db> {
         }
     }
 
-    /// Emit a diagnostic declaring that the object represented by `node` is not iterable
-    pub(super) fn add_not_iterable(&mut self, node: AnyNodeRef, not_iterable_ty: Type<'db>) {
+    /// Emit a diagnostic declaring that the object represented by `node` is not iterable,
+    /// explaining why.
+    pub(super) fn add_not_iterable(
+        &mut self,
+        node: AnyNodeRef,
+        not_iterable_ty: Type<'db>,
+        reason: NotIterableReason<'db>,
+    ) {
+        let reason = match reason {
+            NotIterableReason::NoDunderIter => {
+                "it has no `__iter__` method and no `__getitem__` method".to_string()
+            }
+            NotIterableReason::DunderIterNotCallable => {
+                "its `__iter__` attribute is not callable".to_string()
+            }
+            NotIterableReason::NoDunderNext { iterator_ty } => format!(
+                "its `__iter__` method returns an object of type `{}`, which has no `__next__` method",
+                iterator_ty.display(self.db)
+            ),
+            NotIterableReason::DunderGetitemDoesNotAcceptInt => {
+                "it has no `__iter__` method and its `__getitem__` method \
+                does not accept `int` arguments"
+                    .to_string()
+            }
+        };
+        self.add(
+            node,
+            Rule::NotIterable,
+            format_args!(
+                "Object of type `{}` is not iterable because {reason}",
+                not_iterable_ty.display(self.db)
+            ),
+        );
+    }
+
+    /// Emit a diagnostic declaring that the object represented by `node` cannot be iterated
+    /// over with `async for`, explaining why.
+    pub(super) fn add_not_async_iterable(
+        &mut self,
+        node: AnyNodeRef,
+        not_iterable_ty: Type<'db>,
+        reason: NotAsyncIterableReason<'db>,
+    ) {
+        let reason = match reason {
+            NotAsyncIterableReason::NoDunderAiter => "it has no `__aiter__` method".to_string(),
+            NotAsyncIterableReason::DunderAiterNotCallable => {
+                "its `__aiter__` attribute is not callable".to_string()
+            }
+            NotAsyncIterableReason::NoDunderAnext { iterator_ty } => format!(
+                "its `__aiter__` method returns an object of type `{}`, which has no `__anext__` method",
+                iterator_ty.display(self.db)
+            ),
+            NotAsyncIterableReason::DunderAnextNotAwaitable { awaitable_ty } => format!(
+                "its `__anext__` method returns an object of type `{}`, which is not awaitable",
+                awaitable_ty.display(self.db)
+            ),
+        };
         self.add(
             node,
-            "not-iterable",
+            Rule::NotAsyncIterable,
             format_args!(
-                "Object of type `{}` is not iterable",
+                "Object of type `{}` is not async-iterable because {reason}",
                 not_iterable_ty.display(self.db)
             ),
         );
@@ -169,7 +453,7 @@ impl<'db> TypeCheckDiagnosticsBuilder<'db> {
     ) {
         self.add(
             node,
-            "not-iterable",
+            Rule::NotIterable,
             format_args!(
                 "Object of type `{}` is not iterable because its `__iter__` method is possibly unbound",
                 element_ty.display(self.db)
@@ -188,7 +472,7 @@ impl<'db> TypeCheckDiagnosticsBuilder<'db> {
     ) {
         self.add(
             node,
-            "index-out-of-bounds",
+            Rule::IndexOutOfBounds,
             format_args!(
                 "Index {index} is out of bounds for {kind} `{}` with length {length}",
                 tuple_ty.display(self.db)
@@ -205,7 +489,7 @@ impl<'db> TypeCheckDiagnosticsBuilder<'db> {
     ) {
         self.add(
             node,
-            "non-subscriptable",
+            Rule::NonSubscriptable,
             format_args!(
                 "Cannot subscript object of type `{}` with no `{method}` method",
                 non_subscriptable_ty.display(self.db)
@@ -221,7 +505,7 @@ impl<'db> TypeCheckDiagnosticsBuilder<'db> {
     ) {
         self.add(
             import_node.into(),
-            "unresolved-import",
+            Rule::UnresolvedImport,
             format_args!(
                 "Cannot resolve import `{}{}`",
                 ".".repeat(level as usize),
@@ -233,7 +517,7 @@ impl<'db> TypeCheckDiagnosticsBuilder<'db> {
     pub(super) fn add_slice_step_size_zero(&mut self, node: AnyNodeRef) {
         self.add(
             node,
-            "zero-stepsize-in-slice",
+            Rule::ZeroStepsizeInSlice,
             format_args!("Slice step size can not be zero"),
         );
     }
@@ -246,19 +530,19 @@ impl<'db> TypeCheckDiagnosticsBuilder<'db> {
     ) {
         match declared_ty {
             Type::ClassLiteral(ClassLiteralType { class }) => {
-                self.add(node, "invalid-assignment", format_args!(
+                self.add(node, Rule::InvalidAssignment, format_args!(
                         "Implicit shadowing of class `{}`; annotate to make it explicit if this is intentional",
                         class.name(self.db)));
             }
             Type::FunctionLiteral(function) => {
-                self.add(node, "invalid-assignment", format_args!(
+                self.add(node, Rule::InvalidAssignment, format_args!(
                         "Implicit shadowing of function `{}`; annotate to make it explicit if this is intentional",
                         function.name(self.db)));
             }
             _ => {
                 self.add(
                     node,
-                    "invalid-assignment",
+                    Rule::InvalidAssignment,
                     format_args!(
                         "Object of type `{}` is not assignable to `{}`",
                         assigned_ty.display(self.db),
@@ -269,13 +553,48 @@ impl<'db> TypeCheckDiagnosticsBuilder<'db> {
         }
     }
 
-    pub(super) fn add_possibly_unresolved_reference(&mut self, expr_name_node: &ast::ExprName) {
+    /// Emit a diagnostic declaring that the type returned by a `return` statement
+    /// is not assignable to the function's declared return type.
+    pub(super) fn add_return_type_mismatch(
+        &mut self,
+        node: AnyNodeRef,
+        declared_ty: Type<'db>,
+        actual_ty: Type<'db>,
+    ) {
+        self.add(
+            node,
+            Rule::ReturnTypeMismatch,
+            format_args!(
+                "Object of type `{}` is not assignable to return type `{}`",
+                actual_ty.display(self.db),
+                declared_ty.display(self.db),
+            ),
+        );
+    }
+
+    /// Emit a diagnostic declaring that the name is only bound on some control flow paths.
+    ///
+    /// `reason`, if known, names the kind of construct responsible for the unbound path, so
+    /// that e.g. an import-with-fallback pattern gets import-specific wording.
+    pub(super) fn add_possibly_unresolved_reference(
+        &mut self,
+        expr_name_node: &ast::ExprName,
+        reason: Option<UnboundReason>,
+    ) {
         let ast::ExprName { id, .. } = expr_name_node;
 
+        let context = match reason {
+            Some(UnboundReason::Conditional) => "; it is only bound in some conditional branches",
+            Some(UnboundReason::Loop) => "; it is only bound in a loop body that may not run",
+            Some(UnboundReason::Try) => "; it is only bound if the `try` block succeeds",
+            Some(UnboundReason::TryImport) => "; it is only bound when the `try` import succeeds",
+            Some(UnboundReason::ScopeStart) | None => "",
+        };
+
         self.add(
             expr_name_node.into(),
-            "possibly-unresolved-reference",
-            format_args!("Name `{id}` used when possibly not defined"),
+            Rule::PossiblyUnresolvedReference,
+            format_args!("Name `{id}` used when possibly not defined{context}"),
         );
     }
 
@@ -284,15 +603,38 @@ impl<'db> TypeCheckDiagnosticsBuilder<'db> {
 
         self.add(
             expr_name_node.into(),
-            "unresolved-reference",
+            Rule::UnresolvedReference,
             format_args!("Name `{id}` used when not defined"),
         );
     }
 
+    /// Return `true` if a diagnostic for `rule` has already been reported within `range`.
+    ///
+    /// Used to report a diagnostic only once for an expression whose subexpressions would
+    /// each trigger it again, such as an attribute chain with a possibly-`None` link.
+    pub(super) fn has_diagnostic_in_range(&self, rule: Rule, range: TextRange) -> bool {
+        self.diagnostics
+            .iter()
+            .any(|diagnostic| diagnostic.rule == rule && range.contains_range(diagnostic.range))
+    }
+
     /// Adds a new diagnostic.
     ///
     /// The diagnostic does not get added if the rule isn't enabled for this file.
-    pub(super) fn add(&mut self, node: AnyNodeRef, rule: &str, message: std::fmt::Arguments) {
+    pub(super) fn add(&mut self, node: AnyNodeRef, rule: Rule, message: std::fmt::Arguments) {
+        self.add_with_range(node.range(), rule, message);
+    }
+
+    /// Adds a new diagnostic anchored on `range` rather than on a whole node; used when
+    /// only a sub-expression of the node being checked is at fault.
+    ///
+    /// The diagnostic does not get added if the rule isn't enabled for this file.
+    pub(super) fn add_with_range(
+        &mut self,
+        range: TextRange,
+        rule: Rule,
+        message: std::fmt::Arguments,
+    ) {
         if !self.db.is_file_open(self.file) {
             return;
         }
@@ -305,9 +647,10 @@ impl<'db> TypeCheckDiagnosticsBuilder<'db> {
 
         self.diagnostics.push(TypeCheckDiagnostic {
             file: self.file,
-            rule: rule.to_string(),
+            severity: rule.default_severity(),
+            rule,
             message: message.to_string(),
-            range: node.range(),
+            range,
         });
     }
 
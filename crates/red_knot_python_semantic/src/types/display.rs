@@ -8,7 +8,7 @@ use ruff_python_literal::escape::AsciiEscape;
 
 use crate::types::{
     ClassLiteralType, InstanceType, IntersectionType, KnownClass, StringLiteralType,
-    SubclassOfType, Type, UnionType,
+    SubclassOfType, Type, TypeGuardKind, UnionType, TYPE_COMPLEXITY_LIMIT,
 };
 use crate::Db;
 use rustc_hash::FxHashMap;
@@ -75,6 +75,39 @@ impl Display for DisplayRepresentation<'_> {
                 };
                 f.write_str(representation)
             }
+            Type::GenericInstance(generic) => {
+                write!(
+                    f,
+                    "{}[{}]",
+                    generic.class(self.db).name(self.db),
+                    generic.specialization(self.db).display(self.db)
+                )
+            }
+            Type::Iterator(iterator) => f.write_str(iterator.class(self.db).name(self.db)),
+            Type::Coroutine(coroutine) => f.write_str(coroutine.class(self.db).name(self.db)),
+            Type::BoundSuper(bound_super) => {
+                write!(f, "<super: {}>", bound_super.pivot_class(self.db).name(self.db))
+            }
+            Type::Callable(callable) => {
+                f.write_str("(")?;
+                match (
+                    callable.parameter_types(self.db),
+                    callable.param_spec(self.db),
+                ) {
+                    (Some(parameter_types), _) => parameter_types.display(self.db).fmt(f)?,
+                    (None, Some(param_spec)) => write!(f, "**{}", param_spec.name(self.db))?,
+                    (None, None) => f.write_str("...")?,
+                }
+                f.write_str(") -> ")?;
+                callable.return_ty(self.db).display(self.db).fmt(f)
+            }
+            Type::TypeGuard(guard) => {
+                let special_form = match guard.kind(self.db) {
+                    TypeGuardKind::TypeGuard => "TypeGuard",
+                    TypeGuardKind::TypeIs => "TypeIs",
+                };
+                write!(f, "{special_form}[{}]", guard.guarded_ty(self.db).display(self.db))
+            }
             // `[Type::Todo]`'s display should be explicit that is not a valid display of
             // any other type
             Type::Todo => f.write_str("@Todo"),
@@ -151,6 +184,21 @@ impl Display for DisplayUnionType<'_> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         let elements = self.ty.elements(self.db);
 
+        // Unions above the complexity limit are summarized rather than printed in full:
+        // the complete rendering could be tens of kilobytes and makes diagnostics
+        // unusable.
+        if Type::Union(*self.ty).size(self.db) > TYPE_COMPLEXITY_LIMIT {
+            let noun = if elements
+                .iter()
+                .all(|element| matches!(element, Type::Tuple(_)))
+            {
+                "tuple types"
+            } else {
+                "types"
+            };
+            return write!(f, "<union of {} {noun}>", elements.len());
+        }
+
         // Group condensed-display types by kind.
         let mut grouped_condensed_kinds = FxHashMap::default();
 
@@ -360,7 +408,9 @@ mod tests {
     use ruff_db::system::{DbWithTestSystem, SystemPathBuf};
 
     use crate::db::tests::TestDb;
-    use crate::types::{global_symbol, SliceLiteralType, StringLiteralType, Type, UnionType};
+    use crate::types::{
+        global_symbol, IntersectionBuilder, SliceLiteralType, StringLiteralType, Type, UnionType,
+    };
     use crate::{Program, ProgramSettings, PythonVersion, SearchPathSettings};
 
     fn setup_db() -> TestDb {
@@ -375,6 +425,7 @@ mod tests {
             &db,
             &ProgramSettings {
                 target_version: PythonVersion::default(),
+                respect_noqa: false,
                 search_paths: SearchPathSettings::new(src_root),
             },
         )
@@ -436,6 +487,70 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_union_display_is_pep_604_style() -> anyhow::Result<()> {
+        let mut db = setup_db();
+
+        db.write_dedented(
+            "src/main.py",
+            "
+            class A: ...
+            class B: ...
+
+            a = A()
+            b = B()
+            ",
+        )?;
+        let mod_file = system_path_to_file(&db, "src/main.py").expect("file to exist");
+        let a = global_symbol(&db, mod_file, "a").expect_type();
+        let b = global_symbol(&db, mod_file, "b").expect_type();
+
+        assert_eq!(
+            UnionType::from_elements(&db, &[a, b]).display(&db).to_string(),
+            "A | B"
+        );
+        // A union with `None` is displayed as written, never as `Optional[...]`.
+        assert_eq!(
+            UnionType::from_elements(&db, &[a, Type::none(&db)])
+                .display(&db)
+                .to_string(),
+            "A | None"
+        );
+        // A single-element union collapses to its only element.
+        assert_eq!(
+            UnionType::from_elements(&db, &[a]).display(&db).to_string(),
+            "A"
+        );
+        // Negative elements of an intersection are displayed with a `~` prefix.
+        assert_eq!(
+            IntersectionBuilder::new(&db)
+                .add_positive(a)
+                .add_negative(b)
+                .build()
+                .display(&db)
+                .to_string(),
+            "A & ~B"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_large_union_display_is_summarized() {
+        let db = setup_db();
+
+        // A union of 200 single-element tuple types has structural size 401,
+        // which is far above `TYPE_COMPLEXITY_LIMIT`.
+        let mut elements: Vec<Type> = (0..200)
+            .map(|i| Type::tuple(&db, &[Type::IntLiteral(i)]))
+            .collect();
+        let union = UnionType::from_elements(&db, &elements).expect_union();
+        assert_eq!(union.display(&db).to_string(), "<union of 200 tuple types>");
+
+        elements.push(Type::none(&db));
+        let union = UnionType::from_elements(&db, &elements).expect_union();
+        assert_eq!(union.display(&db).to_string(), "<union of 201 types>");
+    }
+
     #[test]
     fn test_slice_literal_display() {
         let db = setup_db();
@@ -31,7 +31,9 @@ use std::num::NonZeroU32;
 use itertools::Itertools;
 use ruff_db::files::File;
 use ruff_db::parsed::parsed_module;
+use ruff_python_ast::visitor::{walk_expr, walk_stmt, Visitor};
 use ruff_python_ast::{self as ast, AnyNodeRef, Expr, ExprContext, UnaryOp};
+use ruff_text_size::{Ranged, TextRange};
 use rustc_hash::{FxHashMap, FxHashSet};
 use salsa;
 use salsa::plumbing::AsId;
@@ -45,29 +47,48 @@ use crate::semantic_index::definition::{
 };
 use crate::semantic_index::expression::Expression;
 use crate::semantic_index::semantic_index;
-use crate::semantic_index::symbol::{NodeWithScopeKind, NodeWithScopeRef, ScopeId};
-use crate::semantic_index::SemanticIndex;
+use crate::semantic_index::symbol::{
+    FileScopeId, NodeWithScopeKind, NodeWithScopeRef, ScopeId, ScopeKind, ScopedSymbolId,
+};
+use crate::semantic_index::{BindingWithConstraintsIterator, SemanticIndex, UnboundReason};
 use crate::stdlib::builtins_module_scope;
-use crate::types::diagnostic::{TypeCheckDiagnostics, TypeCheckDiagnosticsBuilder};
+use crate::types::diagnostic::{Rule, TypeCheckDiagnostics, TypeCheckDiagnosticsBuilder};
 use crate::types::mro::MroErrorKind;
 use crate::types::unpacker::{UnpackResult, Unpacker};
 use crate::types::{
-    bindings_ty, builtins_symbol, declarations_ty, global_symbol, symbol, typing_extensions_symbol,
-    Boundness, Class, ClassLiteralType, FunctionType, InstanceType, IntersectionBuilder,
-    IntersectionType, IterationOutcome, KnownClass, KnownFunction, KnownInstanceType,
-    MetaclassCandidate, MetaclassErrorKind, SliceLiteralType, Symbol, Truthiness, TupleType, Type,
-    TypeArrayDisplay, TypeVarBoundOrConstraints, TypeVarInstance, UnionBuilder, UnionType,
+    bindings_ty, builtins_symbol, declarations_ty, global_symbol, star_import_symbol, symbol,
+    typing_extensions_symbol, Boundness, BoundSuperType, CallArguments, CallableType, Class,
+    ClassLiteralType, CoroutineType, FunctionType, GenericInstanceType, InstanceType,
+    IntersectionBuilder, IntersectionType, IterationOutcome, IteratorType, KnownClass,
+    KnownFunction, KnownInstanceType,
+    MetaclassCandidate, MetaclassErrorKind, NotCallableError, ParamSpecInstance, SliceLiteralType,
+    Symbol, Truthiness, TupleType, Type, TypeArrayDisplay, TypeGuardKind, TypeGuardType,
+    TypeVarBoundOrConstraints, TypeVarInstance, UnionBuilder, UnionType, TYPE_COMPLEXITY_LIMIT,
 };
 use crate::unpack::Unpack;
 use crate::util::subscript::{PyIndex, PySlice};
-use crate::Db;
+use crate::{Db, Program, PythonVersion};
 
+use super::mro::ClassBase;
 use super::string_annotation::parse_string_annotation;
 
+/// Cycle recovery for [`infer_scope_types()`]: for now, an empty inference
+/// TODO fixpoint iteration
+fn infer_scope_types_cycle_recovery<'db>(
+    _db: &'db dyn Db,
+    _cycle: &salsa::Cycle,
+    scope: ScopeId<'db>,
+) -> TypeInference<'db> {
+    tracing::trace!("infer_scope_types_cycle_recovery");
+    // TODO we don't fill in expression types here, which can later cause a panic when
+    // looking up an expression type.
+    TypeInference::empty(scope)
+}
+
 /// Infer all types for a [`ScopeId`], including all definitions and expressions in that scope.
 /// Use when checking a scope, or needing to provide a type for an arbitrary expression in the
 /// scope.
-#[salsa::tracked(return_ref)]
+#[salsa::tracked(return_ref, recovery_fn=infer_scope_types_cycle_recovery)]
 pub(crate) fn infer_scope_types<'db>(db: &'db dyn Db, scope: ScopeId<'db>) -> TypeInference<'db> {
     let file = scope.file(db);
     let _span =
@@ -164,6 +185,104 @@ pub(crate) fn infer_expression_types<'db>(
     TypeInferenceBuilder::new(db, InferenceRegion::Expression(expression), index).finish()
 }
 
+/// Cycle recovery for [`infer_generator_yield_ty()`]: for now, just `Unknown`
+/// TODO fixpoint iteration
+fn infer_generator_yield_ty_cycle_recovery<'db>(
+    _db: &'db dyn Db,
+    _cycle: &salsa::Cycle,
+    _function: FunctionType<'db>,
+) -> Option<Type<'db>> {
+    tracing::trace!("infer_generator_yield_ty_cycle_recovery");
+    Some(Type::Unknown)
+}
+
+/// Infer the union of the types yielded by the body of a generator function.
+///
+/// Returns `None` if the function's body contains no `yield` or `yield from` expression,
+/// i.e. if the function is not a generator function.
+#[salsa::tracked(recovery_fn=infer_generator_yield_ty_cycle_recovery)]
+pub(crate) fn infer_generator_yield_ty<'db>(
+    db: &'db dyn Db,
+    function: FunctionType<'db>,
+) -> Option<Type<'db>> {
+    let scope = function.body_scope(db);
+    let function_node = scope.node(db).expect_function();
+
+    let mut visitor = YieldCollector::default();
+    visitor.visit_body(&function_node.body);
+    if visitor.yields.is_empty() {
+        return None;
+    }
+
+    let inference = infer_scope_types(db, scope);
+    let mut builder = UnionBuilder::new(db);
+    for yielded in visitor.yields {
+        let yield_ty = match yielded {
+            YieldedValue::Yield(Some(value)) => {
+                inference.expression_ty(value.scoped_ast_id(db, scope))
+            }
+            // A bare `yield` yields `None`.
+            YieldedValue::Yield(None) => Type::none(db),
+            // `yield from` re-yields the values yielded by the sub-iterable. Any non-iterable
+            // diagnostic is already emitted when the function body itself is checked.
+            YieldedValue::YieldFrom(value) => {
+                match inference
+                    .expression_ty(value.scoped_ast_id(db, scope))
+                    .iterate(db)
+                {
+                    IterationOutcome::Iterable { element_ty }
+                    | IterationOutcome::PossiblyUnboundDunderIter { element_ty, .. } => element_ty,
+                    IterationOutcome::NotIterable { .. }
+                    | IterationOutcome::NotAsyncIterable { .. } => Type::Unknown,
+                }
+            }
+        };
+        builder = builder.add(yield_ty);
+    }
+    Some(builder.build())
+}
+
+/// A `yield` or `yield from` expression found in a function body.
+enum YieldedValue<'a> {
+    Yield(Option<&'a ast::Expr>),
+    YieldFrom(&'a ast::Expr),
+}
+
+/// Collects all `yield` and `yield from` expressions belonging to a function body,
+/// without descending into nested function, class, lambda, or comprehension scopes.
+#[derive(Default)]
+struct YieldCollector<'a> {
+    yields: Vec<YieldedValue<'a>>,
+}
+
+impl<'a> Visitor<'a> for YieldCollector<'a> {
+    fn visit_stmt(&mut self, stmt: &'a ast::Stmt) {
+        match stmt {
+            ast::Stmt::FunctionDef(_) | ast::Stmt::ClassDef(_) => {}
+            _ => walk_stmt(self, stmt),
+        }
+    }
+
+    fn visit_expr(&mut self, expr: &'a ast::Expr) {
+        match expr {
+            ast::Expr::Yield(ast::ExprYield { value, .. }) => {
+                self.yields.push(YieldedValue::Yield(value.as_deref()));
+                walk_expr(self, expr);
+            }
+            ast::Expr::YieldFrom(ast::ExprYieldFrom { value, .. }) => {
+                self.yields.push(YieldedValue::YieldFrom(value));
+                walk_expr(self, expr);
+            }
+            ast::Expr::Lambda(_)
+            | ast::Expr::Generator(_)
+            | ast::Expr::ListComp(_)
+            | ast::Expr::SetComp(_)
+            | ast::Expr::DictComp(_) => {}
+            _ => walk_expr(self, expr),
+        }
+    }
+}
+
 /// Infer the types for an [`Unpack`] operation.
 ///
 /// This infers the expression type and performs structural match against the target expression
@@ -341,6 +460,12 @@ pub(super) struct TypeInferenceBuilder<'db> {
     /// is a stub file but we're still in a non-deferred region.
     deferred_state: DeferredExpressionState,
 
+    /// The declared return type of the function whose body is currently being inferred,
+    /// if inferring a function-body scope and the function has a return annotation.
+    ///
+    /// `return` statements in the body are checked for assignability against this type.
+    declared_return_ty: Option<Type<'db>>,
+
     diagnostics: TypeCheckDiagnosticsBuilder<'db>,
 }
 
@@ -371,6 +496,7 @@ impl<'db> TypeInferenceBuilder<'db> {
             region,
             file,
             deferred_state: DeferredExpressionState::None,
+            declared_return_ty: None,
             types: TypeInference::empty(scope),
             diagnostics: TypeCheckDiagnosticsBuilder::new(db, file),
         }
@@ -464,6 +590,128 @@ impl<'db> TypeInferenceBuilder<'db> {
 
         // TODO: Only call this function when diagnostics are enabled.
         self.check_class_definitions();
+        self.check_conflicting_declarations();
+
+        if matches!(node, NodeWithScopeKind::Module) {
+            self.check_dunder_all();
+        }
+    }
+
+    /// Look for symbols that are re-annotated with a conflicting type in this scope, and
+    /// report the conflict at the re-declaration site.
+    ///
+    /// A declaration accompanied by a binding is the supported way to intentionally
+    /// re-declare a symbol with a new type (and conflicts reaching a binding are reported
+    /// there; see [`Self::add_binding`]), so only bare annotations are considered here.
+    /// Conflicts between declarations in another module are deliberately not reported;
+    /// those are that module's problem.
+    fn check_conflicting_declarations(&mut self) {
+        // In stubs the last declaration wins, so alternative declarations under
+        // mutually-exclusive version branches are expected, not conflicts.
+        if self.file.is_stub(self.db.upcast()) {
+            return;
+        }
+
+        let mut declarations_by_symbol: FxHashMap<
+            ScopedSymbolId,
+            Vec<(&ast::StmtAnnAssign, Type<'db>)>,
+        > = FxHashMap::default();
+        for (definition, ty) in &self.types.declarations {
+            let DefinitionKind::AnnotatedAssignment(ann_assign) = definition.kind(self.db) else {
+                continue;
+            };
+            if ann_assign.value.is_some() {
+                continue;
+            }
+            declarations_by_symbol
+                .entry(definition.symbol(self.db))
+                .or_default()
+                .push((ann_assign.node(), *ty));
+        }
+
+        let symbol_table = self.index.symbol_table(self.scope().file_scope_id(self.db));
+        let mut conflicts = vec![];
+        for (symbol, mut declarations) in declarations_by_symbol {
+            if declarations.len() < 2 {
+                continue;
+            }
+            declarations.sort_by_key(|(node, _)| node.start());
+            let (_, first_ty) = declarations[0];
+            let conflicting: Vec<Type<'db>> = declarations[1..]
+                .iter()
+                .map(|(_, ty)| *ty)
+                // `Todo` types only conflict due to limitations in our current model.
+                .filter(|ty| !first_ty.is_equivalent_to(self.db, *ty) && !ty.is_todo())
+                .collect();
+            if conflicting.is_empty() || first_ty.is_todo() {
+                continue;
+            }
+            let (last_node, _) = declarations[declarations.len() - 1];
+            let symbol_name = symbol_table.symbol(symbol).name().clone();
+            conflicts.push((last_node, symbol_name, first_ty, conflicting));
+        }
+
+        // Report in source order, independent of the map's iteration order.
+        conflicts.sort_by_key(|(node, ..)| node.start());
+        for (node, symbol_name, first_ty, conflicting) in conflicts {
+            let types: Vec<Type<'db>> = [first_ty].into_iter().chain(conflicting).collect();
+            self.diagnostics.add(
+                node.into(),
+                Rule::ConflictingDeclarations,
+                format_args!(
+                    "Conflicting declared types for `{symbol_name}`: {}",
+                    types.display(self.db)
+                ),
+            );
+        }
+    }
+
+    /// Check the module's `__all__` export list, if there is one: every element must be a
+    /// string literal naming a symbol that is actually defined in the module.
+    ///
+    /// This is a purely syntactic check of top-level `__all__ = [...]` (or tuple)
+    /// assignments; dynamically computed export lists are left alone.
+    fn check_dunder_all(&mut self) {
+        let parsed = parsed_module(self.db.upcast(), self.file);
+        let symbol_table = self.index.symbol_table(FileScopeId::global());
+
+        for stmt in &parsed.syntax().body {
+            let ast::Stmt::Assign(assign) = stmt else {
+                continue;
+            };
+            if !assign.targets.iter().any(
+                |target| matches!(target, ast::Expr::Name(name) if name.id == "__all__"),
+            ) {
+                continue;
+            }
+            let elements = match &*assign.value {
+                ast::Expr::List(list) => &list.elts,
+                ast::Expr::Tuple(tuple) => &tuple.elts,
+                _ => continue,
+            };
+            for element in elements {
+                let ast::Expr::StringLiteral(literal) = element else {
+                    self.diagnostics.add(
+                        element.into(),
+                        Rule::InvalidAll,
+                        format_args!("Elements of `__all__` must be string literals"),
+                    );
+                    continue;
+                };
+                let name = literal.value.to_str();
+                let is_defined = symbol_table
+                    .symbol_id_by_name(name)
+                    .map(|symbol_id| symbol_table.symbol(symbol_id))
+                    .is_some_and(|symbol| symbol.is_bound() || symbol.is_declared());
+                if !is_defined {
+                    self.diagnostics.add(
+                        element.into(),
+                        Rule::UndefinedExport,
+                        format_args!("`__all__` includes `{name}`, which is not defined in the module"),
+                    );
+                }
+            }
+        }
     }
 
     /// Iterate over all class definitions to check that the definition will not cause an exception
@@ -496,7 +744,7 @@ impl<'db> TypeInferenceBuilder<'db> {
             if class.is_cyclically_defined(self.db) {
                 self.diagnostics.add(
                     class_node.into(),
-                    "cyclic-class-def",
+                    Rule::CyclicClassDef,
                     format_args!(
                         "Cyclic definition of `{}` or bases of `{}` (class cannot inherit from itself)",
                         class.name(self.db),
@@ -516,7 +764,7 @@ impl<'db> TypeInferenceBuilder<'db> {
                         for (index, duplicate) in duplicates {
                             self.diagnostics.add(
                                 (&base_nodes[*index]).into(),
-                                "duplicate-base",
+                                Rule::DuplicateBase,
                                 format_args!("Duplicate base class `{}`", duplicate.name(self.db)),
                             );
                         }
@@ -526,7 +774,7 @@ impl<'db> TypeInferenceBuilder<'db> {
                         for (index, base_ty) in bases {
                             self.diagnostics.add(
                                 (&base_nodes[*index]).into(),
-                                "invalid-base",
+                                Rule::InvalidBase,
                                 format_args!(
                                     "Invalid class base with type `{}` (all bases must be a class, `Any`, `Unknown` or `Todo`)",
                                     base_ty.display(self.db)
@@ -536,7 +784,7 @@ impl<'db> TypeInferenceBuilder<'db> {
                     }
                     MroErrorKind::UnresolvableMro { bases_list } => self.diagnostics.add(
                         class_node.into(),
-                        "inconsistent-mro",
+                        Rule::InconsistentMro,
                         format_args!(
                             "Cannot create a consistent method resolution order (MRO) for class `{}` with bases list `[{}]`",
                             class.name(self.db),
@@ -566,7 +814,7 @@ impl<'db> TypeInferenceBuilder<'db> {
                         if *candidate1_is_base_class {
                             self.diagnostics.add(
                                 node,
-                                "conflicting-metaclass",
+                                Rule::ConflictingMetaclass,
                                 format_args!(
                                     "The metaclass of a derived class (`{class}`) must be a subclass of the metaclasses of all its bases, \
                                     but `{metaclass1}` (metaclass of base class `{base1}`) and `{metaclass2}` (metaclass of base class `{base2}`) \
@@ -581,7 +829,7 @@ impl<'db> TypeInferenceBuilder<'db> {
                         } else {
                             self.diagnostics.add(
                                 node,
-                                "conflicting-metaclass",
+                                Rule::ConflictingMetaclass,
                                 format_args!(
                                     "The metaclass of a derived class (`{class}`) must be a subclass of the metaclasses of all its bases, \
                                     but `{metaclass_of_class}` (metaclass of `{class}`) and `{metaclass_of_base}` (metaclass of base class `{base}`) \
@@ -724,7 +972,7 @@ impl<'db> TypeInferenceBuilder<'db> {
 
         self.diagnostics.add(
             expr.into(),
-            "division-by-zero",
+            Rule::DivisionByZero,
             format_args!(
                 "Cannot {op} object of type `{}` {by_zero}",
                 left.display(self.db)
@@ -749,7 +997,7 @@ impl<'db> TypeInferenceBuilder<'db> {
                 let symbol_name = symbol_table.symbol(binding.symbol(self.db)).name();
                 self.diagnostics.add(
                     node,
-                    "conflicting-declarations",
+                    Rule::ConflictingDeclarations,
                     format_args!(
                         "Conflicting declared types for `{symbol_name}`: {}",
                         conflicting.display(self.db)
@@ -765,6 +1013,20 @@ impl<'db> TypeInferenceBuilder<'db> {
             bound_ty = declared_ty;
         };
 
+        // Point at the binding that created an over-limit type, so that the source of a
+        // type summarized in diagnostics can be found.
+        let size = bound_ty.size(self.db);
+        if size > TYPE_COMPLEXITY_LIMIT {
+            self.diagnostics.add(
+                node,
+                Rule::TypeTooComplex,
+                format_args!(
+                    "Inferred type of this binding has structural size {size}, which exceeds the \
+                     complexity limit ({TYPE_COMPLEXITY_LIMIT}); it will be summarized in diagnostics"
+                ),
+            );
+        }
+
         self.types.bindings.insert(binding, bound_ty);
     }
 
@@ -779,7 +1041,7 @@ impl<'db> TypeInferenceBuilder<'db> {
         } else {
             self.diagnostics.add(
                 node,
-                "invalid-declaration",
+                Rule::InvalidDeclaration,
                 format_args!(
                     "Cannot declare type `{}` for inferred type `{}`",
                     ty.display(self.db),
@@ -848,9 +1110,32 @@ impl<'db> TypeInferenceBuilder<'db> {
     }
 
     fn infer_function_body(&mut self, function: &ast::StmtFunctionDef) {
+        self.declared_return_ty = self.function_declared_return_ty(function);
         self.infer_body(&function.body);
     }
 
+    /// Returns the declared return type of `function`, if it has a return annotation.
+    ///
+    /// The annotation itself is inferred as part of the function's definition in the enclosing
+    /// scope; here we just read it back via the function's signature, so that deferred
+    /// annotations (stubs, `from __future__ import annotations`) are handled consistently.
+    fn function_declared_return_ty(
+        &self,
+        function: &ast::StmtFunctionDef,
+    ) -> Option<Type<'db>> {
+        function.returns.as_ref()?;
+        let definition = self.index.definition(function);
+        let function_ty = infer_definition_types(self.db, definition)
+            .binding_ty(definition)
+            .into_function_literal()?;
+        match function_ty.signature(self.db).return_ty {
+            // The annotation on an `async` function describes the value wrapped in the
+            // returned coroutine object; that's what `return` statements in the body produce.
+            Type::Coroutine(coroutine) => Some(coroutine.result(self.db)),
+            return_ty => Some(return_ty),
+        }
+    }
+
     fn infer_body(&mut self, suite: &[ast::Stmt]) {
         for statement in suite {
             self.infer_statement(statement);
@@ -924,6 +1209,8 @@ impl<'db> TypeInferenceBuilder<'db> {
             .map(|decorator| self.infer_decorator(decorator))
             .collect();
 
+        let deprecated = self.deprecated_decorator_message(decorator_list);
+
         for default in parameters
             .iter_non_variadic_params()
             .filter_map(|param| param.default.as_deref())
@@ -958,6 +1245,7 @@ impl<'db> TypeInferenceBuilder<'db> {
             function_kind,
             body_scope,
             decorator_tys,
+            deprecated,
         ));
 
         self.add_declaration_with_binding(function.into(), definition, function_ty, function_ty);
@@ -1036,8 +1324,63 @@ impl<'db> TypeInferenceBuilder<'db> {
                 annotated_ty,
             );
         } else {
-            self.add_binding(parameter.into(), definition, annotated_ty);
+            let ty = self.self_parameter_ty(parameter).unwrap_or(annotated_ty);
+            self.add_binding(parameter.into(), definition, ty);
+        }
+    }
+
+    /// The type of an implicit `self` or `cls` parameter: if `parameter` is the unannotated
+    /// first positional parameter of a function defined directly in a class body, it is bound
+    /// to an instance of that class when the method is called, or to the class itself
+    /// (`type[C]`) if the method is decorated with `@classmethod`.
+    fn self_parameter_ty(&self, parameter: &ast::Parameter) -> Option<Type<'db>> {
+        let NodeWithScopeKind::Function(function) = self.scope().node(self.db) else {
+            return None;
+        };
+        let parameters = &function.parameters;
+        let first_parameter = parameters
+            .posonlyargs
+            .first()
+            .or_else(|| parameters.args.first())?;
+        if first_parameter.parameter.range() != parameter.range() {
+            return None;
+        }
+        fn decorator_name(expression: &ast::Expr) -> Option<&str> {
+            match expression {
+                ast::Expr::Name(name) => Some(name.id.as_str()),
+                ast::Expr::Attribute(attribute) => Some(attribute.attr.as_str()),
+                _ => None,
+            }
+        }
+        let mut is_classmethod = false;
+        for decorator in &function.decorator_list {
+            match decorator_name(&decorator.expression) {
+                // `@staticmethod` removes the implicit first parameter entirely.
+                Some("staticmethod") => return None,
+                // `@classmethod` binds it to the class rather than an instance.
+                Some("classmethod") => is_classmethod = true,
+                _ => {}
+            }
+        }
+        // A generic method's body scope is nested inside its type-parameter scope.
+        let mut enclosing_scope = self
+            .index
+            .parent_scope_id(self.scope().file_scope_id(self.db))?;
+        while self.index.scope(enclosing_scope).kind() == ScopeKind::Annotation {
+            enclosing_scope = self.index.scope(enclosing_scope).parent()?;
         }
+        let NodeWithScopeKind::Class(class_node) = self.index.scope(enclosing_scope).node() else {
+            return None;
+        };
+        let definition = self.index.definition(class_node.node());
+        let ClassLiteralType { class } = infer_definition_types(self.db, definition)
+            .binding_ty(definition)
+            .into_class_literal()?;
+        Some(if is_classmethod {
+            Type::subclass_of(class)
+        } else {
+            Type::instance(class)
+        })
     }
 
     fn infer_class_definition_statement(&mut self, class: &ast::StmtClassDef) {
@@ -1062,6 +1405,8 @@ impl<'db> TypeInferenceBuilder<'db> {
             self.infer_decorator(decorator);
         }
 
+        let deprecated = self.deprecated_decorator_message(decorator_list);
+
         let body_scope = self
             .index
             .node_scope(NodeWithScopeRef::Class(class_node))
@@ -1069,7 +1414,7 @@ impl<'db> TypeInferenceBuilder<'db> {
 
         let maybe_known_class = KnownClass::try_from_file(self.db, self.file, name);
 
-        let class = Class::new(self.db, &name.id, body_scope, maybe_known_class);
+        let class = Class::new(self.db, &name.id, body_scope, maybe_known_class, deprecated);
         let class_ty = Type::class_literal(class);
 
         self.add_declaration_with_binding(class_node.into(), definition, class_ty, class_ty);
@@ -1145,6 +1490,8 @@ impl<'db> TypeInferenceBuilder<'db> {
 
         self.infer_body(body);
 
+        let mut caught_exceptions: Vec<Type<'db>> = vec![];
+
         for handler in handlers {
             let ast::ExceptHandler::ExceptHandler(handler) = handler;
             let ast::ExceptHandlerExceptHandler {
@@ -1164,6 +1511,10 @@ impl<'db> TypeInferenceBuilder<'db> {
                 self.infer_optional_expression(handled_exceptions.as_deref());
             }
 
+            if let Some(handled_exceptions) = handled_exceptions.as_deref() {
+                self.check_unreachable_except_clause(handled_exceptions, &mut caught_exceptions);
+            }
+
             self.infer_body(body);
         }
 
@@ -1171,6 +1522,56 @@ impl<'db> TypeInferenceBuilder<'db> {
         self.infer_body(finalbody);
     }
 
+    /// Flag an `except` clause all of whose exception classes are already caught
+    /// by an earlier clause of the same `try` statement, making it unreachable.
+    ///
+    /// `caught_exceptions` accumulates the instance types caught by the clauses
+    /// seen so far.
+    fn check_unreachable_except_clause(
+        &mut self,
+        handled_exceptions: &ast::Expr,
+        caught_exceptions: &mut Vec<Type<'db>>,
+    ) {
+        let caught_by_clause: Vec<Type<'db>> = match self.expression_ty(handled_exceptions) {
+            Type::ClassLiteral(ClassLiteralType { class }) => vec![Type::instance(class)],
+            Type::Tuple(tuple) => {
+                let mut instance_types = vec![];
+                for element in tuple.elements(self.db) {
+                    let Some(ClassLiteralType { class }) = element.into_class_literal() else {
+                        // A dynamic element could catch anything;
+                        // don't try to reason about this clause.
+                        return;
+                    };
+                    instance_types.push(Type::instance(class));
+                }
+                instance_types
+            }
+            // A dynamic exception type could catch anything.
+            _ => return,
+        };
+
+        let unreachable = !caught_by_clause.is_empty()
+            && caught_by_clause.iter().all(|exception| {
+                caught_exceptions
+                    .iter()
+                    .any(|earlier| exception.is_subtype_of(self.db, *earlier))
+            });
+
+        if unreachable {
+            self.diagnostics.add(
+                handled_exceptions.into(),
+                Rule::UnreachableExcept,
+                format_args!(
+                    "`except` clause is unreachable because `{}` is already caught by an earlier clause",
+                    UnionType::from_elements(self.db, caught_by_clause.iter().copied())
+                        .display(self.db)
+                ),
+            );
+        }
+
+        caught_exceptions.extend(caught_by_clause);
+    }
+
     fn infer_with_statement(&mut self, with_statement: &ast::StmtWith) {
         let ast::StmtWith {
             range: _,
@@ -1248,7 +1649,7 @@ impl<'db> TypeInferenceBuilder<'db> {
             (Symbol::Unbound, Symbol::Unbound) => {
                 self.diagnostics.add(
                     context_expression.into(),
-                    "invalid-context-manager",
+                    Rule::InvalidContextManager,
                     format_args!(
                         "Object of type `{}` cannot be used with `with` because it doesn't implement `__enter__` and `__exit__`",
                         context_expression_ty.display(self.db)
@@ -1259,7 +1660,7 @@ impl<'db> TypeInferenceBuilder<'db> {
             (Symbol::Unbound, _) => {
                 self.diagnostics.add(
                     context_expression.into(),
-                    "invalid-context-manager",
+                    Rule::InvalidContextManager,
                     format_args!(
                         "Object of type `{}` cannot be used with `with` because it doesn't implement `__enter__`",
                         context_expression_ty.display(self.db)
@@ -1271,7 +1672,7 @@ impl<'db> TypeInferenceBuilder<'db> {
                 if enter_boundness == Boundness::PossiblyUnbound {
                     self.diagnostics.add(
                         context_expression.into(),
-                        "invalid-context-manager",
+                        Rule::InvalidContextManager,
                         format_args!(
                             "Object of type `{context_expression}` cannot be used with `with` because the method `__enter__` is possibly unbound",
                             context_expression = context_expression_ty.display(self.db),
@@ -1280,12 +1681,12 @@ impl<'db> TypeInferenceBuilder<'db> {
                 }
 
                 let target_ty = enter_ty
-                    .call(self.db, &[context_expression_ty])
+                    .call(self.db, &CallArguments::positional([context_expression_ty]))
                     .return_ty_result(self.db, context_expression.into(), &mut self.diagnostics)
                     .unwrap_or_else(|err| {
                         self.diagnostics.add(
                             context_expression.into(),
-                            "invalid-context-manager",
+                            Rule::InvalidContextManager,
                             format_args!("
                                 Object of type `{context_expression}` cannot be used with `with` because the method `__enter__` of type `{enter_ty}` is not callable", context_expression = context_expression_ty.display(self.db), enter_ty = enter_ty.display(self.db)
                             ),
@@ -1297,7 +1698,7 @@ impl<'db> TypeInferenceBuilder<'db> {
                     Symbol::Unbound => {
                         self.diagnostics.add(
                             context_expression.into(),
-                            "invalid-context-manager",
+                            Rule::InvalidContextManager,
                             format_args!(
                                 "Object of type `{}` cannot be used with `with` because it doesn't implement `__exit__`",
                                 context_expression_ty.display(self.db)
@@ -1310,7 +1711,7 @@ impl<'db> TypeInferenceBuilder<'db> {
                         if exit_boundness == Boundness::PossiblyUnbound {
                             self.diagnostics.add(
                                 context_expression.into(),
-                                "invalid-context-manager",
+                                Rule::InvalidContextManager,
                                 format_args!(
                                     "Object of type `{context_expression}` cannot be used with `with` because the method `__exit__` is possibly unbound",
                                     context_expression = context_expression_ty.display(self.db),
@@ -1321,12 +1722,12 @@ impl<'db> TypeInferenceBuilder<'db> {
                         if exit_ty
                             .call(
                                 self.db,
-                                &[
+                                &CallArguments::positional([
                                     context_manager_ty,
                                     Type::none(self.db),
                                     Type::none(self.db),
                                     Type::none(self.db),
-                                ],
+                                ]),
                             )
                             .return_ty_result(
                                 self.db,
@@ -1337,7 +1738,7 @@ impl<'db> TypeInferenceBuilder<'db> {
                         {
                             self.diagnostics.add(
                                 context_expression.into(),
-                                "invalid-context-manager",
+                                Rule::InvalidContextManager,
                                 format_args!(
                                     "Object of type `{context_expression}` cannot be used with `with` because the method `__exit__` of type `{exit_ty}` is not callable",
                                     context_expression = context_expression_ty.display(self.db),
@@ -1417,7 +1818,7 @@ impl<'db> TypeInferenceBuilder<'db> {
                 if elts.len() < 2 {
                     self.diagnostics.add(
                         expr.into(),
-                        "invalid-typevar-constraints",
+                        Rule::InvalidTypevarConstraints,
                         format_args!("TypeVar must have at least two constrained types"),
                     );
                     self.infer_expression(expr);
@@ -1456,11 +1857,16 @@ impl<'db> TypeInferenceBuilder<'db> {
     ) {
         let ast::TypeParamParamSpec {
             range: _,
-            name: _,
+            name,
             default,
         } = node;
-        self.infer_optional_expression(default.as_deref());
-        self.add_declaration_with_binding(node.into(), definition, Type::Todo, Type::Todo);
+        let default_ty = self.infer_optional_type_expression(default.as_deref());
+        let ty = Type::KnownInstance(KnownInstanceType::ParamSpec(ParamSpecInstance::new(
+            self.db,
+            name.id.clone(),
+            default_ty,
+        )));
+        self.add_declaration_with_binding(node.into(), definition, ty, ty);
     }
 
     fn infer_typevartuple_definition(
@@ -1588,6 +1994,11 @@ impl<'db> TypeInferenceBuilder<'db> {
                     self.infer_assignment_target(element, value);
                 }
             }
+            ast::Expr::Attribute(attribute) => {
+                self.infer_standalone_expression(value);
+                self.infer_expression(target);
+                self.check_attribute_assignment(attribute, self.expression_ty(value));
+            }
             _ => {
                 // TODO: Remove this once we handle all possible assignment targets.
                 self.infer_standalone_expression(value);
@@ -1596,6 +2007,101 @@ impl<'db> TypeInferenceBuilder<'db> {
         }
     }
 
+    /// Check an assignment to an attribute target against the property definitions of the
+    /// object's class, if any.
+    fn check_attribute_assignment(&mut self, attribute: &ast::ExprAttribute, value_ty: Type<'db>) {
+        let ast::ExprAttribute {
+            value: object,
+            attr,
+            ..
+        } = attribute;
+        let object_ty = self.expression_ty(object);
+        let Type::Instance(InstanceType { class }) = object_ty else {
+            return;
+        };
+        // All fields of a frozen dataclass are read-only.
+        if class.is_frozen_dataclass(self.db) {
+            if let Some(field) = class
+                .dataclass_fields(self.db)
+                .iter()
+                .find(|field| field.name.as_str() == attr.as_str())
+            {
+                self.diagnostics.add(
+                    attribute.into(),
+                    Rule::InvalidAttributeAssignment,
+                    format_args!(
+                        "Cannot assign to field `{}` of frozen dataclass `{}`",
+                        field.name,
+                        class.name(self.db),
+                    ),
+                );
+                return;
+            }
+        }
+        if class.property_getter(self.db, attr).is_none() {
+            // TODO: check assignments to regular attributes once we track their declared
+            // types.
+            return;
+        }
+        let Some(setter) = class.property_setter(self.db, attr) else {
+            self.diagnostics.add(
+                attribute.into(),
+                Rule::InvalidAttributeAssignment,
+                format_args!(
+                    "Cannot assign to read-only property `{attr}` on object of type `{}`",
+                    object_ty.display(self.db),
+                ),
+            );
+            return;
+        };
+        // The assigned value is passed to the setter's second positional parameter,
+        // after `self`.
+        let Some(parameter_ty) = setter
+            .internal_signature(self.db)
+            .positional_parameter_types()
+            .get(1)
+            .copied()
+        else {
+            return;
+        };
+        if !value_ty.is_assignable_to(self.db, parameter_ty) {
+            self.diagnostics.add(
+                attribute.into(),
+                Rule::InvalidAssignment,
+                format_args!(
+                    "Object of type `{}` is not assignable to property `{attr}` of type `{}`",
+                    value_ty.display(self.db),
+                    parameter_ty.display(self.db),
+                ),
+            );
+        }
+    }
+
+    /// Flag deletion of an attribute backed by a read-only property.
+    fn check_attribute_deletion(&mut self, attribute: &ast::ExprAttribute) {
+        let ast::ExprAttribute {
+            value: object,
+            attr,
+            ..
+        } = attribute;
+        let object_ty = self.expression_ty(object);
+        let Type::Instance(InstanceType { class }) = object_ty else {
+            return;
+        };
+        if class.property_getter(self.db, attr).is_some()
+            && class.property_setter(self.db, attr).is_none()
+        {
+            self.diagnostics.add(
+                attribute.into(),
+                Rule::InvalidAttributeAssignment,
+                format_args!(
+                    "Cannot delete read-only property `{attr}` on object of type `{}`",
+                    object_ty.display(self.db),
+                ),
+            );
+        }
+    }
+
     fn infer_assignment_definition(
         &mut self,
         assignment: &AssignmentDefinitionKind<'db>,
@@ -1720,10 +2226,38 @@ impl<'db> TypeInferenceBuilder<'db> {
                 })
             }
             Type::Instance(InstanceType { class }) => {
+                // In-place `|` and `&` on the builtin collection classes (e.g. the PEP 584
+                // `dict |= other`) are routed through the same special-casing as their
+                // binary counterparts rather than through the in-place dunder, whose stub
+                // return type we cannot yet resolve precisely.
+                if matches!(
+                    class.known(self.db),
+                    Some(KnownClass::Dict | KnownClass::Set | KnownClass::FrozenSet)
+                ) && matches!(op, ast::Operator::BitOr | ast::Operator::BitAnd)
+                {
+                    return self
+                        .infer_binary_expression_type(target_type, value_type, op)
+                        .unwrap_or_else(|| {
+                            self.diagnostics.add(
+                                assignment.into(),
+                                Rule::UnsupportedOperator,
+                                format_args!(
+                                    "Operator `{op}=` is unsupported between objects of type `{}` and `{}`",
+                                    target_type.display(self.db),
+                                    value_type.display(self.db)
+                                ),
+                            );
+                            Type::Unknown
+                        });
+                }
+
                 if let Symbol::Type(class_member, boundness) =
                     class.class_member(self.db, op.in_place_dunder())
                 {
-                    let call = class_member.call(self.db, &[target_type, value_type]);
+                    let call = class_member.call(
+                        self.db,
+                        &CallArguments::positional([target_type, value_type]),
+                    );
                     let augmented_return_ty = match call.return_ty_result(
                         self.db,
                         AnyNodeRef::StmtAugAssign(assignment),
@@ -1733,7 +2267,7 @@ impl<'db> TypeInferenceBuilder<'db> {
                         Err(e) => {
                             self.diagnostics.add(
                                 assignment.into(),
-                                "unsupported-operator",
+                                Rule::UnsupportedOperator,
                                 format_args!(
                                     "Operator `{op}=` is unsupported between objects of type `{}` and `{}`",
                                     target_type.display(self.db),
@@ -1754,7 +2288,7 @@ impl<'db> TypeInferenceBuilder<'db> {
                                 .unwrap_or_else(|| {
                                     self.diagnostics.add(
                                         assignment.into(),
-                                        "unsupported-operator",
+                                        Rule::UnsupportedOperator,
                                         format_args!(
                                             "Operator `{op}=` is unsupported between objects of type `{}` and `{}`",
                                             left_ty.display(self.db),
@@ -1783,7 +2317,7 @@ impl<'db> TypeInferenceBuilder<'db> {
             .unwrap_or_else(|| {
                 self.diagnostics.add(
                     assignment.into(),
-                    "unsupported-operator",
+                    Rule::UnsupportedOperator,
                     format_args!(
                         "Operator `{op}=` is unsupported between objects of type `{}` and `{}`",
                         left_ty.display(self.db),
@@ -1873,8 +2407,9 @@ impl<'db> TypeInferenceBuilder<'db> {
         let iterable_ty = self.infer_standalone_expression(iterable);
 
         let loop_var_value_ty = if is_async {
-            // TODO(Alex): async iterables/iterators!
-            Type::Todo
+            iterable_ty
+                .iterate_async(self.db)
+                .unwrap_with_diagnostic(iterable.into(), &mut self.diagnostics)
         } else {
             iterable_ty
                 .iterate(self.db)
@@ -2003,8 +2538,6 @@ impl<'db> TypeInferenceBuilder<'db> {
         definition: Definition<'db>,
     ) {
         // TODO:
-        // - Absolute `*` imports (`from collections import *`)
-        // - Relative `*` imports (`from ...foo import *`)
         // - Submodule imports (`from collections import abc`,
         //   where `abc` is a submodule of the `collections` package)
         //
@@ -2042,12 +2575,26 @@ impl<'db> TypeInferenceBuilder<'db> {
                         asname: _,
                     } = alias;
 
+                    // A star import binds the module's exported names in the current
+                    // scope. The names don't appear in the symbol table individually;
+                    // they resolve lazily through the scope's `*` symbol, which we bind
+                    // to the imported module itself (see `star_import_symbol`).
+                    if &name.id == "*" {
+                        self.add_declaration_with_binding(
+                            alias.into(),
+                            definition,
+                            module_ty,
+                            module_ty,
+                        );
+                        return;
+                    }
+
                     match module_ty.member(self.db, &ast::name::Name::new(&name.id)) {
                         Symbol::Type(ty, boundness) => {
                             if boundness == Boundness::PossiblyUnbound {
                                 self.diagnostics.add(
                                     AnyNodeRef::Alias(alias),
-                                    "possibly-unbound-import",
+                                    Rule::PossiblyUnboundImport,
                                     format_args!("Member `{name}` of module `{module_name}` is possibly unbound",),
                                 );
                             }
@@ -2057,7 +2604,7 @@ impl<'db> TypeInferenceBuilder<'db> {
                         Symbol::Unbound => {
                             self.diagnostics.add(
                                 AnyNodeRef::Alias(alias),
-                                "unresolved-import",
+                                Rule::UnresolvedImport,
                                 format_args!("Module `{module_name}` has no member `{name}`",),
                             );
                             Type::Unknown
@@ -2099,13 +2646,42 @@ impl<'db> TypeInferenceBuilder<'db> {
     }
 
     fn infer_return_statement(&mut self, ret: &ast::StmtReturn) {
-        self.infer_optional_expression(ret.value.as_deref());
+        if !matches!(self.scope().scope(self.db).kind(), ScopeKind::Function) {
+            // A `return` outside a function body is a `SyntaxError` at runtime.
+            self.diagnostics.add(
+                ret.into(),
+                Rule::ReturnOutsideFunction,
+                format_args!("`return` statement outside of a function"),
+            );
+        }
+        // TODO: `return` statements in generator functions return the `StopIteration` value and
+        // should not be checked against the (generator-typed) annotation. Subscripted generator
+        // annotations currently infer to `Todo`, so no false positives arise from this yet.
+        if let Some(value) = ret.value.as_deref() {
+            let value_ty = self.infer_expression(value);
+            if let Some(declared_ty) = self.declared_return_ty {
+                if !value_ty.is_assignable_to(self.db, declared_ty) {
+                    self.diagnostics
+                        .add_return_type_mismatch(value.into(), declared_ty, value_ty);
+                }
+            }
+        } else if let Some(declared_ty) = self.declared_return_ty {
+            // A bare `return` implicitly returns `None`.
+            let none_ty = Type::none(self.db);
+            if !none_ty.is_assignable_to(self.db, declared_ty) {
+                self.diagnostics
+                    .add_return_type_mismatch(ret.into(), declared_ty, none_ty);
+            }
+        }
     }
 
     fn infer_delete_statement(&mut self, delete: &ast::StmtDelete) {
         let ast::StmtDelete { range: _, targets } = delete;
         for target in targets {
             self.infer_expression(target);
+            if let ast::Expr::Attribute(attribute) = target {
+                self.check_attribute_deletion(attribute);
+            }
         }
     }
 
@@ -2122,25 +2698,66 @@ impl<'db> TypeInferenceBuilder<'db> {
         self.infer_expression(expression)
     }
 
-    fn infer_arguments(&mut self, arguments: &ast::Arguments) -> Vec<Type<'db>> {
-        let mut types = Vec::with_capacity(
-            arguments
-                .args
-                .len()
-                .saturating_add(arguments.keywords.len()),
-        );
+    /// Return the message of a `@warnings.deprecated` (PEP 702) decorator in
+    /// `decorator_list`, if there is one.
+    ///
+    /// Per the spec the message must be a string literal, so calls whose first
+    /// argument is anything else are not treated as deprecations. The decorator
+    /// expressions must already have been inferred.
+    fn deprecated_decorator_message(&self, decorator_list: &[ast::Decorator]) -> Option<Box<str>> {
+        decorator_list.iter().find_map(|decorator| {
+            let ast::Expr::Call(call) = &decorator.expression else {
+                return None;
+            };
+            let Some(ClassLiteralType { class }) =
+                self.expression_ty(&call.func).into_class_literal()
+            else {
+                return None;
+            };
+            if !class.is_known(self.db, KnownClass::Deprecated) {
+                return None;
+            }
+            let Some(ast::Expr::StringLiteral(message)) = call.arguments.args.first() else {
+                return None;
+            };
+            Some(Box::from(message.value.to_str()))
+        })
+    }
 
-        types.extend(arguments.args.iter().map(|arg| self.infer_expression(arg)));
+    fn infer_arguments(&mut self, arguments: &ast::Arguments) -> CallArguments<'db> {
+        let mut call_arguments = CallArguments::default();
+
+        for arg in &*arguments.args {
+            if let ast::Expr::Starred(ast::ExprStarred { value, .. }) = arg {
+                self.infer_expression(arg);
+                // Unpacking a fixed-length tuple provides exactly its elements, in
+                // order, so they can take part in regular positional checking; any
+                // other iterable provides an unknown number of arguments.
+                match self.expression_ty(value) {
+                    Type::Tuple(tuple) => call_arguments
+                        .positional
+                        .extend(tuple.elements(self.db).iter().copied()),
+                    iterable_ty => call_arguments.variadic.push(iterable_ty),
+                }
+            } else {
+                call_arguments.positional.push(self.infer_expression(arg));
+            }
+        }
 
-        types.extend(arguments.keywords.iter().map(
-            |ast::Keyword {
-                 range: _,
-                 arg: _,
-                 value,
-             }| self.infer_expression(value),
-        ));
+        for ast::Keyword {
+            range: _,
+            arg,
+            value,
+        } in &*arguments.keywords
+        {
+            let value_ty = self.infer_expression(value);
+            match arg {
+                Some(name) => call_arguments.keyword.push((name.id.clone(), value_ty)),
+                None => call_arguments.keyword_variadic.push(value_ty),
+            }
+        }
 
-        types
+        call_arguments
     }
 
     fn infer_optional_expression(&mut self, expression: Option<&ast::Expr>) -> Option<Type<'db>> {
@@ -2236,10 +2853,7 @@ impl<'db> TypeInferenceBuilder<'db> {
                 .map(Type::IntLiteral)
                 .unwrap_or_else(|| KnownClass::Int.to_instance(self.db)),
             ast::Number::Float(_) => KnownClass::Float.to_instance(self.db),
-            ast::Number::Complex { .. } => builtins_symbol(self.db, "complex")
-                .ignore_possibly_unbound()
-                .unwrap_or(Type::Unknown)
-                .to_instance(self.db),
+            ast::Number::Complex { .. } => KnownClass::Complex.to_instance(self.db),
         }
     }
 
@@ -2259,9 +2873,13 @@ impl<'db> TypeInferenceBuilder<'db> {
     }
 
     fn infer_bytes_literal_expression(&mut self, literal: &ast::ExprBytesLiteral) -> Type<'db> {
-        // TODO: ignoring r/R prefixes for now, should normalize bytes values
-        let bytes: Vec<u8> = literal.value.bytes().collect();
-        Type::bytes_literal(self.db, &bytes)
+        if literal.value.len() <= Self::MAX_STRING_LITERAL_SIZE {
+            // TODO: ignoring r/R prefixes for now, should normalize bytes values
+            let bytes: Vec<u8> = literal.value.bytes().collect();
+            Type::bytes_literal(self.db, &bytes)
+        } else {
+            KnownClass::Bytes.to_instance(self.db)
+        }
     }
 
     fn infer_fstring_expression(&mut self, fstring: &ast::ExprFString) -> Type<'db> {
@@ -2288,6 +2906,22 @@ impl<'db> TypeInferenceBuilder<'db> {
                                 } = expression;
                                 let ty = self.infer_expression(expression);
 
+                                if let Some(format_spec) = format_spec {
+                                    for spec_element in &format_spec.elements {
+                                        if let ast::FStringElement::Expression(spec_expression) =
+                                            spec_element
+                                        {
+                                            self.infer_expression(&spec_expression.expression);
+                                        }
+                                    }
+                                    // A conversion flag (`!r`, `!s`, `!a`) converts the value
+                                    // to a `str` first, so the spec never reaches the value's
+                                    // own `__format__` method.
+                                    if conversion.is_none() {
+                                        self.check_dunder_format(expression, ty);
+                                    }
+                                }
+
                                 // TODO: handle format specifiers by calling a method
                                 // (`Type::format`?) that handles the `__format__` method.
                                 // Conversion flags should be handled before calling `__format__`.
@@ -2295,10 +2929,12 @@ impl<'db> TypeInferenceBuilder<'db> {
                                 if !conversion.is_none() || format_spec.is_some() {
                                     collector.add_expression();
                                 } else {
-                                    if let Type::StringLiteral(literal) = ty.str(self.db) {
-                                        collector.push_str(literal.value(self.db));
-                                    } else {
-                                        collector.add_expression();
+                                    match ty.str(self.db) {
+                                        Type::StringLiteral(literal) => {
+                                            collector.push_str(literal.value(self.db));
+                                        }
+                                        Type::LiteralString => collector.add_literal_string(),
+                                        _ => collector.add_expression(),
                                     }
                                 }
                             }
@@ -2313,13 +2949,48 @@ impl<'db> TypeInferenceBuilder<'db> {
         collector.ty(self.db)
     }
 
-    fn infer_ellipsis_literal_expression(
-        &mut self,
-        _literal: &ast::ExprEllipsisLiteral,
-    ) -> Type<'db> {
-        builtins_symbol(self.db, "Ellipsis")
-            .ignore_possibly_unbound()
-            .unwrap_or(Type::Unknown)
+    /// Check that a value interpolated with a format spec in an f-string supports formatting:
+    /// applying the spec calls `type(value).__format__(value, spec)`.
+    ///
+    /// `object.__format__` (which rejects any non-empty spec at runtime) satisfies this check,
+    /// so in practice it only catches objects whose class is missing from the MRO entirely.
+    ///
+    /// TODO: check the spec itself against the `__format__` signatures known from typeshed,
+    /// so that e.g. `f"{x:.2f}"` requires `x` to support float formatting.
+    fn check_dunder_format(&mut self, node: &ast::Expr, ty: Type<'db>) {
+        match ty.to_meta_type(self.db).member(self.db, "__format__") {
+            Symbol::Type(_, Boundness::Bound) => {}
+            Symbol::Type(_, Boundness::PossiblyUnbound) => {
+                self.diagnostics.add(
+                    node.into(),
+                    Rule::CallPossiblyUnboundMethod,
+                    format_args!(
+                        "Method `__format__` of type `{}` is possibly unbound",
+                        ty.display(self.db),
+                    ),
+                );
+            }
+            Symbol::Unbound => {
+                self.diagnostics.add(
+                    node.into(),
+                    Rule::UnsupportedFormat,
+                    format_args!(
+                        "Object of type `{}` does not support format specifiers \
+                         (missing `__format__` method)",
+                        ty.display(self.db),
+                    ),
+                );
+            }
+        }
+    }
+
+    fn infer_ellipsis_literal_expression(
+        &mut self,
+        _literal: &ast::ExprEllipsisLiteral,
+    ) -> Type<'db> {
+        builtins_symbol(self.db, "Ellipsis")
+            .ignore_possibly_unbound()
+            .unwrap_or(Type::Unknown)
     }
 
     fn infer_tuple_expression(&mut self, tuple: &ast::ExprTuple) -> Type<'db> {
@@ -2548,8 +3219,9 @@ impl<'db> TypeInferenceBuilder<'db> {
         };
 
         let target_ty = if is_async {
-            // TODO: async iterables/iterators! -- Alex
-            Type::Todo
+            iterable_ty
+                .iterate_async(self.db)
+                .unwrap_with_diagnostic(iterable.into(), &mut self.diagnostics)
         } else {
             iterable_ty
                 .iterate(self.db)
@@ -2648,12 +3320,471 @@ impl<'db> TypeInferenceBuilder<'db> {
             arguments,
         } = call_expression;
 
-        // TODO: proper typed call signature, representing keyword args etc
-        let arg_types = self.infer_arguments(arguments);
+        let call_arguments = self.infer_arguments(arguments);
+        // The special-cased calls below only look at the positional arguments.
+        let arg_types = call_arguments.positional_types();
         let function_type = self.infer_expression(func);
-        function_type
-            .call(self.db, arg_types.as_slice())
-            .unwrap_with_diagnostic(self.db, func.as_ref().into(), &mut self.diagnostics)
+
+        // We don't support generics yet, so we special-case calls to `zip(...)` in order
+        // to infer a precise element type for iteration over the resulting object.
+        if let Type::ClassLiteral(ClassLiteralType { class }) = function_type {
+            if class.is_known(self.db, KnownClass::Zip) {
+                return self.infer_zip_call(arguments, arg_types, class);
+            }
+            if class.is_known(self.db, KnownClass::Enumerate) {
+                return self.infer_enumerate_call(arguments, arg_types, class);
+            }
+            if class.is_known(self.db, KnownClass::Map) {
+                return self.infer_map_call(arguments, arg_types, class);
+            }
+            if class.is_known(self.db, KnownClass::Filter) {
+                return self.infer_filter_call(arguments, arg_types, class);
+            }
+            if class.is_known(self.db, KnownClass::Super) {
+                if let Some(bound_super_ty) = self.infer_super_call(arg_types) {
+                    return bound_super_ty;
+                }
+            }
+        }
+
+        if let Type::FunctionLiteral(function) = function_type {
+            if function.is_known(self.db, KnownFunction::Replace) {
+                if let Some(replaced_ty) = self.infer_dataclass_replace_call(arguments, arg_types) {
+                    return replaced_ty;
+                }
+            }
+            if matches!(
+                function.known(self.db),
+                Some(KnownFunction::Sorted | KnownFunction::Min | KnownFunction::Max)
+            ) {
+                self.check_orderable_call(arguments, arg_types);
+            }
+            if function.is_known(self.db, KnownFunction::Gather) {
+                return self.infer_gather_call(arguments, arg_types);
+            }
+        }
+
+        self.check_deprecated_call(func, function_type, &call_arguments);
+
+        match function_type
+            .call(self.db, &call_arguments)
+            .return_ty_result(self.db, func.as_ref().into(), &mut self.diagnostics)
+        {
+            Ok(return_ty) => return_ty,
+            Err(error) => {
+                let error = self.locate_non_callable_union_element(func, error);
+                error.add_diagnostic(self.db, func.as_ref().into(), &mut self.diagnostics);
+                error.return_ty()
+            }
+        }
+    }
+
+    /// Emit a `deprecated` diagnostic if the call target is marked with
+    /// `@warnings.deprecated` (PEP 702): a deprecated function, an overloaded function
+    /// whose selected overload is deprecated, or instantiation of a class that is (or
+    /// inherits from) a deprecated one.
+    fn check_deprecated_call(
+        &mut self,
+        func: &ast::Expr,
+        callee_ty: Type<'db>,
+        call_arguments: &CallArguments<'db>,
+    ) {
+        match callee_ty {
+            // A deprecated method accessed as an attribute is already flagged by the
+            // attribute inference; don't report the call a second time.
+            Type::FunctionLiteral(_) if func.is_attribute_expr() => {}
+            Type::FunctionLiteral(function) => {
+                let deprecated = if function.overloads(self.db).is_empty() {
+                    function.deprecated(self.db)
+                } else if call_arguments.has_keywords() || call_arguments.has_variadic() {
+                    // We can't tell which overload applies.
+                    &None
+                } else {
+                    // Only the deprecation of the overload selected for this call counts.
+                    match function.matching_overload(self.db, call_arguments.positional_types()) {
+                        Some(overload) => overload.deprecated(self.db),
+                        None => &None,
+                    }
+                };
+                if let Some(message) = deprecated {
+                    self.diagnostics.add(
+                        func.into(),
+                        Rule::Deprecated,
+                        format_args!(
+                            "Function `{}` is deprecated: {message}",
+                            function.name(self.db)
+                        ),
+                    );
+                }
+            }
+            Type::ClassLiteral(ClassLiteralType { class }) => {
+                for superclass in class.iter_mro(self.db) {
+                    let ClassBase::Class(superclass) = superclass else {
+                        continue;
+                    };
+                    if let Some(message) = superclass.deprecated(self.db) {
+                        self.diagnostics.add(
+                            func.into(),
+                            Rule::Deprecated,
+                            format_args!(
+                                "Class `{}` is deprecated: {message}",
+                                superclass.name(self.db)
+                            ),
+                        );
+                        break;
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// When a call to a union fails because of a single non-callable element, try to map
+    /// that element back to a sub-expression of the callee so the diagnostic underlines
+    /// the precise culprit rather than the whole callee.
+    fn locate_non_callable_union_element(
+        &self,
+        callee: &ast::Expr,
+        error: NotCallableError<'db>,
+    ) -> NotCallableError<'db> {
+        let NotCallableError::UnionElement {
+            not_callable_ty,
+            called_ty,
+            return_ty,
+            span: None,
+        } = error
+        else {
+            return error;
+        };
+        NotCallableError::UnionElement {
+            not_callable_ty,
+            called_ty,
+            return_ty,
+            span: self.conditional_branch_with_ty(callee, not_callable_ty),
+        }
+    }
+
+    /// Return the range of the branch of a (possibly nested) conditional expression whose
+    /// inferred type is `ty`, if there is exactly one obvious candidate.
+    fn conditional_branch_with_ty(&self, expr: &ast::Expr, ty: Type<'db>) -> Option<TextRange> {
+        let ast::Expr::If(ast::ExprIf { body, orelse, .. }) = expr else {
+            return None;
+        };
+        [body, orelse].into_iter().find_map(|branch| {
+            if self.expression_ty(branch) == ty {
+                Some(branch.range())
+            } else {
+                self.conditional_branch_with_ty(branch, ty)
+            }
+        })
+    }
+
+    /// Infer a call to `dataclasses.replace`: the result has the same dataclass type as the
+    /// first argument, and each keyword argument is checked against the corresponding field.
+    ///
+    /// Returns `None` (falling back to the generic call path) if the first argument is not an
+    /// instance of a dataclass.
+    /// `sorted`, and `min`/`max` over a single iterable, order the elements with `<` unless
+    /// a `key` function is given: flag element types that don't support `__lt__` between
+    /// two of their own instances.
+    fn check_orderable_call(&mut self, arguments: &ast::Arguments, arg_types: &[Type<'db>]) {
+        if arguments.find_keyword("key").is_some() {
+            return;
+        }
+        // `min`/`max` with several positional arguments compare the arguments themselves;
+        // only the single-iterable form is checked here.
+        let [iterable_ty] = arg_types else {
+            return;
+        };
+        let IterationOutcome::Iterable { element_ty } = iterable_ty.iterate(self.db) else {
+            return;
+        };
+        let Type::Instance(instance) = element_ty else {
+            return;
+        };
+        if perform_rich_comparison(self.db, instance, instance, RichCompareOperator::Lt).is_err() {
+            self.diagnostics.add(
+                (&arguments.args[0]).into(),
+                Rule::UnorderableType,
+                format_args!(
+                    "Object of type `{}` is not orderable (unsupported operator `<` between instances)",
+                    element_ty.display(self.db)
+                ),
+            );
+        }
+    }
+
+    /// Infer the type of the awaitable returned by a call to `asyncio.gather(...)`.
+    ///
+    /// We don't support variadic generics, so `gather` is special-cased: awaiting the
+    /// returned object produces a tuple combining the awaited result of each positional
+    /// argument. With `return_exceptions=True`, an exception raised by an argument is
+    /// returned in its slot instead of propagating, so each element is widened with
+    /// `BaseException`.
+    fn infer_gather_call(
+        &mut self,
+        arguments: &ast::Arguments,
+        arg_types: &[Type<'db>],
+    ) -> Type<'db> {
+        let return_exceptions = arguments
+            .find_keyword("return_exceptions")
+            .is_some_and(|keyword| {
+                !matches!(
+                    self.expression_ty(&keyword.value).bool(self.db),
+                    Truthiness::AlwaysFalse
+                )
+            });
+        let element_types: Vec<Type<'db>> = arg_types
+            .iter()
+            .map(|arg_ty| {
+                let result_ty = match arg_ty {
+                    Type::Coroutine(coroutine) => coroutine.result(self.db),
+                    // TODO: support arbitrary awaitables via `__await__`
+                    _ => Type::Todo,
+                };
+                if return_exceptions {
+                    let base_exception_ty = builtins_symbol(self.db, "BaseException")
+                        .ignore_possibly_unbound()
+                        .unwrap_or(Type::Unknown)
+                        .to_instance(self.db);
+                    UnionType::from_elements(self.db, [result_ty, base_exception_ty])
+                } else {
+                    result_ty
+                }
+            })
+            .collect();
+
+        let Some(ClassLiteralType { class }) = KnownClass::CoroutineType
+            .to_class_literal(self.db)
+            .into_class_literal()
+        else {
+            return Type::Todo;
+        };
+        Type::Coroutine(CoroutineType::new(
+            self.db,
+            class,
+            Type::tuple(self.db, &element_types),
+        ))
+    }
+
+    fn infer_dataclass_replace_call(
+        &mut self,
+        arguments: &ast::Arguments,
+        arg_types: &[Type<'db>],
+    ) -> Option<Type<'db>> {
+        let Some(Type::Instance(InstanceType { class })) = arg_types.first().copied() else {
+            return None;
+        };
+        if !class.is_dataclass(self.db) {
+            return None;
+        }
+        let fields = class.dataclass_fields(self.db);
+        for keyword in &*arguments.keywords {
+            let Some(argument_name) = &keyword.arg else {
+                // `replace(obj, **changes)`: nothing to check without the keyword names.
+                continue;
+            };
+            let Some(field) = fields
+                .iter()
+                .find(|field| field.name.as_str() == argument_name.as_str())
+            else {
+                self.diagnostics.add(
+                    keyword.into(),
+                    Rule::InvalidArguments,
+                    format_args!(
+                        "Dataclass `{}` has no field `{argument_name}`",
+                        class.name(self.db),
+                    ),
+                );
+                continue;
+            };
+            let value_ty = self.expression_ty(&keyword.value);
+            if !value_ty.is_assignable_to(self.db, field.ty) {
+                self.diagnostics.add(
+                    keyword.into(),
+                    Rule::InvalidArguments,
+                    format_args!(
+                        "Argument of type `{}` is not assignable to field `{}` of type `{}`",
+                        value_ty.display(self.db),
+                        field.name,
+                        field.ty.display(self.db),
+                    ),
+                );
+            }
+        }
+        Some(Type::instance(class))
+    }
+
+    /// Infer the type of the object constructed by a call to the builtin `zip(...)`.
+    ///
+    /// Iterating over that object yields a tuple combining one element from each of the
+    /// iterables passed as positional arguments. The `strict` keyword only influences
+    /// runtime behavior, never the type of the yielded tuples, so it is ignored here.
+    fn infer_zip_call(
+        &mut self,
+        arguments: &ast::Arguments,
+        arg_types: &[Type<'db>],
+        zip_class: Class<'db>,
+    ) -> Type<'db> {
+        let element_types: Vec<Type<'db>> = arguments
+            .args
+            .iter()
+            .zip(arg_types)
+            .map(|(arg, arg_ty)| {
+                arg_ty
+                    .iterate(self.db)
+                    .unwrap_with_diagnostic(arg.into(), &mut self.diagnostics)
+            })
+            .collect();
+
+        Type::Iterator(IteratorType::new(
+            self.db,
+            zip_class,
+            Type::tuple(self.db, &element_types),
+        ))
+    }
+
+    /// Infer the type of the object constructed by a call to the builtin `enumerate(...)`.
+    ///
+    /// Iterating over that object yields two-element tuples pairing an `int` counter with
+    /// an element of the underlying iterable. The `start` argument only shifts the counter,
+    /// so it never influences the yielded type.
+    fn infer_enumerate_call(
+        &mut self,
+        arguments: &ast::Arguments,
+        arg_types: &[Type<'db>],
+        enumerate_class: Class<'db>,
+    ) -> Type<'db> {
+        let (Some(iterable_node), Some(iterable_ty)) = (arguments.args.first(), arg_types.first())
+        else {
+            // `enumerate()` with no arguments is a `TypeError` at runtime.
+            return Type::instance(enumerate_class);
+        };
+
+        let element_ty = iterable_ty
+            .iterate(self.db)
+            .unwrap_with_diagnostic(iterable_node.into(), &mut self.diagnostics);
+
+        Type::Iterator(IteratorType::new(
+            self.db,
+            enumerate_class,
+            Type::tuple(
+                self.db,
+                &[KnownClass::Int.to_instance(self.db), element_ty],
+            ),
+        ))
+    }
+
+    /// Infer the type of the object constructed by a call to the builtin `map(...)`.
+    ///
+    /// Iterating over that object yields the return type of the callable when applied to
+    /// one element from each of the iterables passed as the remaining positional arguments.
+    fn infer_map_call(
+        &mut self,
+        arguments: &ast::Arguments,
+        arg_types: &[Type<'db>],
+        map_class: Class<'db>,
+    ) -> Type<'db> {
+        let Some((callable_node, iterable_nodes)) = arguments.args.split_first() else {
+            // `map()` with no arguments is a `TypeError` at runtime.
+            return Type::instance(map_class);
+        };
+        let Some((callable_ty, iterable_types)) = arg_types.split_first() else {
+            return Type::instance(map_class);
+        };
+
+        let element_types: Vec<Type<'db>> = iterable_nodes
+            .iter()
+            .zip(iterable_types)
+            .map(|(arg, arg_ty)| {
+                arg_ty
+                    .iterate(self.db)
+                    .unwrap_with_diagnostic(arg.into(), &mut self.diagnostics)
+            })
+            .collect();
+
+        let yields = callable_ty
+            .call(self.db, &CallArguments::positional(element_types))
+            .unwrap_with_diagnostic(self.db, callable_node.into(), &mut self.diagnostics);
+
+        Type::Iterator(IteratorType::new(self.db, map_class, yields))
+    }
+
+    /// Infer the type of the object constructed by a call to the builtin `filter(...)`.
+    ///
+    /// Iterating over that object yields the element type of the iterable passed as the second
+    /// argument. When the predicate is `None`, only truthy elements are kept, so union members
+    /// that are always falsy are excluded from the element type.
+    ///
+    /// TODO: narrow the element type through type-guard predicates as well.
+    fn infer_filter_call(
+        &mut self,
+        arguments: &ast::Arguments,
+        arg_types: &[Type<'db>],
+        filter_class: Class<'db>,
+    ) -> Type<'db> {
+        let ([_, iterable_node], [predicate_ty, iterable_ty]) = (&*arguments.args, arg_types) else {
+            // Any other number of arguments is a `TypeError` at runtime.
+            return Type::instance(filter_class);
+        };
+
+        let element_ty = iterable_ty
+            .iterate(self.db)
+            .unwrap_with_diagnostic(iterable_node.into(), &mut self.diagnostics);
+
+        let predicate_is_none = matches!(
+            predicate_ty,
+            Type::Instance(InstanceType { class }) if class.is_known(self.db, KnownClass::NoneType)
+        );
+        let yields = if predicate_is_none {
+            match element_ty {
+                Type::Union(union) => {
+                    let mut builder = UnionBuilder::new(self.db);
+                    for element in union.elements(self.db) {
+                        if element.bool(self.db) != Truthiness::AlwaysFalse {
+                            builder = builder.add(*element);
+                        }
+                    }
+                    builder.build()
+                }
+                ty if ty.bool(self.db) == Truthiness::AlwaysFalse => Type::Never,
+                ty => ty,
+            }
+        } else {
+            element_ty
+        };
+
+        Type::Iterator(IteratorType::new(self.db, filter_class, yields))
+    }
+
+    /// Infer the type of the proxy object constructed by a call to `super(...)`.
+    ///
+    /// For the zero-argument form the pivot class is the class enclosing the call;
+    /// for the two-argument form `super(C, obj)` it is `C`. Returns `None` for
+    /// call forms we don't understand, falling back to the generic call machinery.
+    fn infer_super_call(&mut self, arg_types: &[Type<'db>]) -> Option<Type<'db>> {
+        let pivot_class = match arg_types {
+            [] => self.enclosing_class()?,
+            [Type::ClassLiteral(ClassLiteralType { class }), _] => *class,
+            _ => return None,
+        };
+        Some(Type::BoundSuper(BoundSuperType::new(self.db, pivot_class)))
+    }
+
+    /// The class whose body encloses the scope currently being inferred, if any.
+    fn enclosing_class(&self) -> Option<Class<'db>> {
+        let mut file_scope_id = self.scope().file_scope_id(self.db);
+        while let Some(parent_scope_id) = self.index.parent_scope_id(file_scope_id) {
+            if let NodeWithScopeKind::Class(class_node) = self.index.scope(parent_scope_id).node() {
+                let definition = self.index.definition(class_node.node());
+                return infer_definition_types(self.db, definition)
+                    .binding_ty(definition)
+                    .into_class_literal()
+                    .map(|ClassLiteralType { class }| class);
+            }
+            file_scope_id = parent_scope_id;
+        }
+        None
     }
 
     fn infer_starred_expression(&mut self, starred: &ast::ExprStarred) -> Type<'db> {
@@ -2675,6 +3806,15 @@ impl<'db> TypeInferenceBuilder<'db> {
     fn infer_yield_expression(&mut self, yield_expression: &ast::ExprYield) -> Type<'db> {
         let ast::ExprYield { range: _, value } = yield_expression;
 
+        if !matches!(self.scope().scope(self.db).kind(), ScopeKind::Function) {
+            // A `yield` outside a function body is a `SyntaxError` at runtime.
+            self.diagnostics.add(
+                yield_expression.into(),
+                Rule::YieldOutsideFunction,
+                format_args!("`yield` expression outside of a function"),
+            );
+        }
+
         self.infer_optional_expression(value.as_deref());
 
         // TODO awaitable type
@@ -2684,6 +3824,15 @@ impl<'db> TypeInferenceBuilder<'db> {
     fn infer_yield_from_expression(&mut self, yield_from: &ast::ExprYieldFrom) -> Type<'db> {
         let ast::ExprYieldFrom { range: _, value } = yield_from;
 
+        if !matches!(self.scope().scope(self.db).kind(), ScopeKind::Function) {
+            // A `yield from` outside a function body is a `SyntaxError` at runtime.
+            self.diagnostics.add(
+                yield_from.into(),
+                Rule::YieldOutsideFunction,
+                format_args!("`yield from` expression outside of a function"),
+            );
+        }
+
         let iterable_ty = self.infer_expression(value);
         iterable_ty
             .iterate(self.db)
@@ -2696,10 +3845,42 @@ impl<'db> TypeInferenceBuilder<'db> {
     fn infer_await_expression(&mut self, await_expression: &ast::ExprAwait) -> Type<'db> {
         let ast::ExprAwait { range: _, value } = await_expression;
 
-        self.infer_expression(value);
+        let value_ty = self.infer_expression(value);
 
-        // TODO awaitable type
-        Type::Todo
+        // `await` is only valid inside an `async` function. Comprehension scopes are
+        // transparent here: an `await` inside a comprehension belongs to the enclosing
+        // function.
+        let in_async_function = self
+            .index
+            .ancestor_scopes(self.scope().file_scope_id(self.db))
+            .find_map(|(_, scope)| match scope.node() {
+                NodeWithScopeKind::Function(function) => Some(function.is_async),
+                NodeWithScopeKind::Module
+                | NodeWithScopeKind::Class(_)
+                | NodeWithScopeKind::Lambda(_) => Some(false),
+                _ => None,
+            })
+            .unwrap_or(false);
+
+        if !in_async_function {
+            self.diagnostics.add(
+                await_expression.into(),
+                Rule::AwaitOutsideAsync,
+                format_args!("`await` outside of an `async` function"),
+            );
+        }
+
+        value_ty.awaited(self.db).unwrap_or_else(|| {
+            self.diagnostics.add(
+                value.as_ref().into(),
+                Rule::InvalidAwait,
+                format_args!(
+                    "Object of type `{}` is not awaitable",
+                    value_ty.display(self.db)
+                ),
+            );
+            Type::Unknown
+        })
     }
 
     /// Look up a name reference that isn't bound in the local scope.
@@ -2746,9 +3927,10 @@ impl<'db> TypeInferenceBuilder<'db> {
             }
 
             // No nonlocal binding, check module globals. Avoid infinite recursion if `self.scope`
-            // already is module globals.
+            // already is module globals: there only the star-import fallback applies, since the
+            // name was already looked up in the global scope's use-def map.
             let global_symbol = if file_scope_id.is_global() {
-                Symbol::Unbound
+                star_import_symbol(self.db, self.file, name)
             } else {
                 global_symbol(self.db, self.file, name)
             };
@@ -2761,7 +3943,7 @@ impl<'db> TypeInferenceBuilder<'db> {
                 if builtins_symbol.is_unbound() && name == "reveal_type" {
                     self.diagnostics.add(
                         name_node.into(),
-                        "undefined-reveal",
+                        Rule::UndefinedReveal,
                         format_args!(
                             "`reveal_type` used without importing it; this is allowed for debugging convenience but will fail at runtime"),
                     );
@@ -2789,24 +3971,32 @@ impl<'db> TypeInferenceBuilder<'db> {
         let use_def = self.index.use_def_map(file_scope_id);
 
         // If we're inferring types of deferred expressions, always treat them as public symbols
-        let (bindings_ty, boundness) = if self.is_deferred() {
+        let (bindings_ty, boundness, unbound_reason) = if self.is_deferred() {
             if let Some(symbol) = self.index.symbol_table(file_scope_id).symbol_id_by_name(id) {
                 (
                     bindings_ty(self.db, use_def.public_bindings(symbol)),
                     use_def.public_boundness(symbol),
+                    self.refine_unbound_reason(
+                        use_def.public_unbound_reason(symbol),
+                        use_def.public_bindings(symbol),
+                    ),
                 )
             } else {
                 assert!(
                     self.deferred_state.in_string_annotation(),
                     "Expected the symbol table to create a symbol for every Name node"
                 );
-                (None, Boundness::PossiblyUnbound)
+                (None, Boundness::PossiblyUnbound, None)
             }
         } else {
             let use_id = name.scoped_use_id(self.db, self.scope());
             (
                 bindings_ty(self.db, use_def.bindings_at_use(use_id)),
                 use_def.use_boundness(use_id),
+                self.refine_unbound_reason(
+                    use_def.use_unbound_reason(use_id),
+                    use_def.bindings_at_use(use_id),
+                ),
             )
         };
 
@@ -2814,7 +4004,8 @@ impl<'db> TypeInferenceBuilder<'db> {
             match self.lookup_name(name) {
                 Symbol::Type(looked_up_ty, looked_up_boundness) => {
                     if looked_up_boundness == Boundness::PossiblyUnbound {
-                        self.diagnostics.add_possibly_unresolved_reference(name);
+                        self.diagnostics
+                            .add_possibly_unresolved_reference(name, unbound_reason);
                     }
 
                     bindings_ty
@@ -2823,7 +4014,8 @@ impl<'db> TypeInferenceBuilder<'db> {
                 }
                 Symbol::Unbound => {
                     if bindings_ty.is_some() {
-                        self.diagnostics.add_possibly_unresolved_reference(name);
+                        self.diagnostics
+                            .add_possibly_unresolved_reference(name, unbound_reason);
                     } else {
                         self.diagnostics.add_unresolved_reference(name);
                     }
@@ -2835,6 +4027,29 @@ impl<'db> TypeInferenceBuilder<'db> {
         }
     }
 
+    /// Refine the [`UnboundReason`] reported for a name: a symbol that is possibly unbound
+    /// because a `try` block may have failed, and whose every reaching binding is an import,
+    /// is the import-with-fallback pattern, which gets import-specific wording.
+    fn refine_unbound_reason(
+        &self,
+        reason: Option<UnboundReason>,
+        mut bindings: BindingWithConstraintsIterator<'_, 'db>,
+    ) -> Option<UnboundReason> {
+        match reason {
+            Some(UnboundReason::Try)
+                if bindings.all(|binding_with_constraints| {
+                    matches!(
+                        binding_with_constraints.binding.kind(self.db),
+                        DefinitionKind::Import(_) | DefinitionKind::ImportFrom(_)
+                    )
+                }) =>
+            {
+                Some(UnboundReason::TryImport)
+            }
+            reason => reason,
+        }
+    }
+
     fn infer_name_expression(&mut self, name: &ast::ExprName) -> Type<'db> {
         match name.ctx {
             ExprContext::Load => self.infer_name_load(name),
@@ -2856,15 +4071,35 @@ impl<'db> TypeInferenceBuilder<'db> {
         match value_ty.member(self.db, &attr.id) {
             Symbol::Type(member_ty, boundness) => {
                 if boundness == Boundness::PossiblyUnbound {
-                    self.diagnostics.add(
-                        attribute.into(),
-                        "possibly-unbound-attribute",
-                        format_args!(
-                            "Attribute `{}` on type `{}` is possibly unbound",
-                            attr.id,
-                            value_ty.display(self.db),
-                        ),
-                    );
+                    if self.attribute_unbound_only_on_none(value_ty, &attr.id) {
+                        self.add_possibly_none_attribute_diagnostic(attribute, value_ty);
+                    } else {
+                        self.diagnostics.add(
+                            attribute.into(),
+                            Rule::PossiblyUnboundAttribute,
+                            format_args!(
+                                "Attribute `{}` on type `{}` is possibly unbound",
+                                attr.id,
+                                value_ty.display(self.db),
+                            ),
+                        );
+                    }
+                }
+
+                // Accessing a deprecated method (or any deprecated function stored as an
+                // attribute) is flagged at the access itself, so that bound uses like
+                // `obj.method()` and `f = obj.method` are both reported.
+                if let Type::FunctionLiteral(function) = member_ty {
+                    if let Some(message) = function.deprecated(self.db) {
+                        self.diagnostics.add(
+                            attribute.into(),
+                            Rule::Deprecated,
+                            format_args!(
+                                "Function `{}` is deprecated: {message}",
+                                function.name(self.db)
+                            ),
+                        );
+                    }
                 }
 
                 member_ty
@@ -2872,7 +4107,7 @@ impl<'db> TypeInferenceBuilder<'db> {
             Symbol::Unbound => {
                 self.diagnostics.add(
                     attribute.into(),
-                    "unresolved-attribute",
+                    Rule::UnresolvedAttribute,
                     format_args!(
                         "Type `{}` has no attribute `{}`",
                         value_ty.display(self.db),
@@ -2884,6 +4119,68 @@ impl<'db> TypeInferenceBuilder<'db> {
         }
     }
 
+    /// Return `true` if `value_ty` is a union in which the attribute `name` resolves on every
+    /// element except `None`. Accessing such an attribute is most likely a missing
+    /// `is not None` guard rather than a genuinely unbound attribute, and gets a more
+    /// specific diagnostic.
+    fn attribute_unbound_only_on_none(&self, value_ty: Type<'db>, name: &str) -> bool {
+        let Type::Union(union) = value_ty else {
+            return false;
+        };
+        let mut any_none = false;
+        for element in union.elements(self.db) {
+            if element.member(self.db, name).is_unbound() {
+                match element {
+                    Type::Instance(InstanceType { class })
+                        if class.is_known(self.db, KnownClass::NoneType) =>
+                    {
+                        any_none = true;
+                    }
+                    _ => return false,
+                }
+            }
+        }
+        any_none
+    }
+
+    /// Report an attribute access on a value that may be `None`.
+    ///
+    /// The diagnostic is emitted at most once per attribute chain: if an earlier link of
+    /// `a.b.c` was already reported, the error-recovery type keeps the chain going, and
+    /// repeating the diagnostic for every later link would just be noise.
+    fn add_possibly_none_attribute_diagnostic(
+        &mut self,
+        attribute: &ast::ExprAttribute,
+        value_ty: Type<'db>,
+    ) {
+        if self
+            .diagnostics
+            .has_diagnostic_in_range(Rule::PossiblyNoneAttribute, attribute.range())
+        {
+            return;
+        }
+        if let Some(chain) = attribute_chain(&attribute.value) {
+            self.diagnostics.add(
+                attribute.into(),
+                Rule::PossiblyNoneAttribute,
+                format_args!(
+                    "Attribute `{}` is accessed on `{chain}`, which may be `None`",
+                    attribute.attr.id
+                ),
+            );
+        } else {
+            self.diagnostics.add(
+                attribute.into(),
+                Rule::PossiblyNoneAttribute,
+                format_args!(
+                    "Attribute `{}` is accessed on an object of type `{}`, which may be `None`",
+                    attribute.attr.id,
+                    value_ty.display(self.db)
+                ),
+            );
+        }
+    }
+
     fn infer_attribute_expression(&mut self, attribute: &ast::ExprAttribute) -> Type<'db> {
         let ast::ExprAttribute {
             value,
@@ -2942,7 +4239,8 @@ impl<'db> TypeInferenceBuilder<'db> {
                 if let Symbol::Type(class_member, _) =
                     class.class_member(self.db, unary_dunder_method)
                 {
-                    let call = class_member.call(self.db, &[operand_type]);
+                    let call =
+                        class_member.call(self.db, &CallArguments::positional([operand_type]));
 
                     match call.return_ty_result(
                         self.db,
@@ -2953,7 +4251,7 @@ impl<'db> TypeInferenceBuilder<'db> {
                         Err(e) => {
                             self.diagnostics.add(
                                 unary.into(),
-                                "unsupported-operator",
+                                Rule::UnsupportedOperator,
                                 format_args!(
                                     "Unary operator `{op}` is unsupported for type `{}`",
                                     operand_type.display(self.db),
@@ -2965,7 +4263,7 @@ impl<'db> TypeInferenceBuilder<'db> {
                 } else {
                     self.diagnostics.add(
                         unary.into(),
-                        "unsupported-operator",
+                        Rule::UnsupportedOperator,
                         format_args!(
                             "Unary operator `{op}` is unsupported for type `{}`",
                             operand_type.display(self.db),
@@ -3006,7 +4304,7 @@ impl<'db> TypeInferenceBuilder<'db> {
             .unwrap_or_else(|| {
                 self.diagnostics.add(
                     binary.into(),
-                    "unsupported-operator",
+                    Rule::UnsupportedOperator,
                     format_args!(
                         "Operator `{op}` is unsupported between objects of type `{}` and `{}`",
                         left_ty.display(self.db),
@@ -3075,10 +4373,48 @@ impl<'db> TypeInferenceBuilder<'db> {
             }
 
             (Type::BytesLiteral(lhs), Type::BytesLiteral(rhs), ast::Operator::Add) => {
-                let bytes = [&**lhs.value(self.db), &**rhs.value(self.db)].concat();
-                Some(Type::bytes_literal(self.db, &bytes))
+                let lhs_value = lhs.value(self.db);
+                let rhs_value = rhs.value(self.db);
+                let ty = if lhs_value.len() + rhs_value.len() <= Self::MAX_STRING_LITERAL_SIZE {
+                    Type::bytes_literal(self.db, &[&**lhs_value, &**rhs_value].concat())
+                } else {
+                    KnownClass::Bytes.to_instance(self.db)
+                };
+                Some(ty)
             }
 
+            (Type::BytesLiteral(b), Type::IntLiteral(n), ast::Operator::Mult)
+            | (Type::IntLiteral(n), Type::BytesLiteral(b), ast::Operator::Mult) => {
+                let ty = if n < 1 {
+                    Type::bytes_literal(self.db, b"")
+                } else if let Ok(n) = usize::try_from(n) {
+                    if n.checked_mul(b.value(self.db).len())
+                        .is_some_and(|new_length| new_length <= Self::MAX_STRING_LITERAL_SIZE)
+                    {
+                        let new_literal = b.value(self.db).repeat(n);
+                        Type::bytes_literal(self.db, &new_literal)
+                    } else {
+                        KnownClass::Bytes.to_instance(self.db)
+                    }
+                } else {
+                    KnownClass::Bytes.to_instance(self.db)
+                };
+                Some(ty)
+            }
+
+            // `bytes` and `str` never support concatenation with each other; without an
+            // explicit arm this combination would fall through to the permissive default.
+            (
+                Type::BytesLiteral(_),
+                Type::StringLiteral(_) | Type::LiteralString,
+                ast::Operator::Add,
+            )
+            | (
+                Type::StringLiteral(_) | Type::LiteralString,
+                Type::BytesLiteral(_),
+                ast::Operator::Add,
+            ) => None,
+
             (Type::StringLiteral(lhs), Type::StringLiteral(rhs), ast::Operator::Add) => {
                 let lhs_value = lhs.value(self.db).to_string();
                 let rhs_value = rhs.value(self.db).as_ref();
@@ -3166,6 +4502,39 @@ impl<'db> TypeInferenceBuilder<'db> {
                 op,
             ),
 
+            // `dict | dict` merges the two mappings (PEP 584). The key and value types of
+            // the result are the unions of the operands' key and value types; until we can
+            // represent generic `dict` instances, the merged type is inferred as a bare
+            // `dict`. Mixing `dict` with a non-mapping falls through to the normal
+            // unsupported-operator path.
+            (Type::Instance(left), Type::Instance(right), ast::Operator::BitOr)
+                if left.class.is_known(self.db, KnownClass::Dict) =>
+            {
+                right
+                    .class
+                    .is_known(self.db, KnownClass::Dict)
+                    .then(|| KnownClass::Dict.to_instance(self.db))
+            }
+
+            // `set` and `frozenset` support union and intersection with each other, and the
+            // result has the class of the left operand. The element type of the result is
+            // the union (for `|`) or the narrower of the two (for `&`) of the operands'
+            // element types, which we also cannot represent without generics yet.
+            (
+                Type::Instance(left),
+                Type::Instance(right),
+                ast::Operator::BitOr | ast::Operator::BitAnd,
+            ) if matches!(
+                left.class.known(self.db),
+                Some(KnownClass::Set | KnownClass::FrozenSet)
+            ) && matches!(
+                right.class.known(self.db),
+                Some(KnownClass::Set | KnownClass::FrozenSet)
+            ) =>
+            {
+                Some(Type::instance(left.class))
+            }
+
             (left_ty @ Type::Instance(left), right_ty @ Type::Instance(right), op) => {
                 if left != right && right.is_instance_of(self.db, left.class) {
                     let reflected_dunder = op.reflected_dunder();
@@ -3174,11 +4543,19 @@ impl<'db> TypeInferenceBuilder<'db> {
                         && rhs_reflected != left.class.class_member(self.db, reflected_dunder)
                     {
                         return right_ty
-                            .call_dunder(self.db, reflected_dunder, &[right_ty, left_ty])
+                            .call_dunder(
+                                self.db,
+                                reflected_dunder,
+                                &CallArguments::positional([right_ty, left_ty]),
+                            )
                             .return_ty(self.db)
                             .or_else(|| {
                                 left_ty
-                                    .call_dunder(self.db, op.dunder(), &[left_ty, right_ty])
+                                    .call_dunder(
+                                        self.db,
+                                        op.dunder(),
+                                        &CallArguments::positional([left_ty, right_ty]),
+                                    )
                                     .return_ty(self.db)
                             });
                     }
@@ -3188,7 +4565,7 @@ impl<'db> TypeInferenceBuilder<'db> {
                     left.class.class_member(self.db, op.dunder())
                 {
                     class_member
-                        .call(self.db, &[left_ty, right_ty])
+                        .call(self.db, &CallArguments::positional([left_ty, right_ty]))
                         .return_ty(self.db)
                 } else {
                     None
@@ -3202,7 +4579,7 @@ impl<'db> TypeInferenceBuilder<'db> {
                             right.class.class_member(self.db, op.reflected_dunder())
                         {
                             class_member
-                                .call(self.db, &[right_ty, left_ty])
+                                .call(self.db, &CallArguments::positional([right_ty, left_ty]))
                                 .return_ty(self.db)
                         } else {
                             None
@@ -3316,7 +4693,7 @@ impl<'db> TypeInferenceBuilder<'db> {
                             // Handle unsupported operators (diagnostic, `bool`/`Unknown` outcome)
                             self.diagnostics.add(
                                 AnyNodeRef::ExprCompare(compare),
-                                "unsupported-operator",
+                                Rule::UnsupportedOperator,
                                 format_args!(
                                     "Operator `{}` is not supported for types `{}` and `{}`{}",
                                     error.op,
@@ -3826,31 +5203,62 @@ impl<'db> TypeInferenceBuilder<'db> {
                     slice_ty,
                 ),
 
+            // Ex) Given `x: list[int]`, `x[0]` is `int`; given `x: dict[str, int]`,
+            // `x["key"]` is `int`.
+            (Type::GenericInstance(generic), slice_ty) => {
+                let specialization = generic.specialization(self.db);
+                match generic.class(self.db).known(self.db) {
+                    Some(KnownClass::List)
+                        if slice_ty
+                            .is_assignable_to(self.db, KnownClass::Int.to_instance(self.db)) =>
+                    {
+                        specialization[0]
+                    }
+                    // Slicing a list produces a list with the same specialization.
+                    Some(KnownClass::List)
+                        if matches!(slice_ty, Type::SliceLiteral(_))
+                            || slice_ty.is_assignable_to(
+                                self.db,
+                                KnownClass::Slice.to_instance(self.db),
+                            ) =>
+                    {
+                        value_ty
+                    }
+                    Some(KnownClass::Dict)
+                        if slice_ty.is_assignable_to(self.db, specialization[0]) =>
+                    {
+                        specialization[1]
+                    }
+                    // Fall back to looking up `__getitem__` on the unspecialized class.
+                    _ => self.infer_subscript_expression_types(
+                        value_node,
+                        Type::instance(generic.class(self.db)),
+                        slice_ty,
+                    ),
+                }
+            }
+
             // Ex) Given `("a", "b", "c", "d")[1]`, return `"b"`
-            (Type::Tuple(tuple_ty), Type::IntLiteral(int)) if i32::try_from(int).is_ok() => {
+            (Type::Tuple(tuple_ty), Type::IntLiteral(int)) => {
                 let elements = tuple_ty.elements(self.db);
-                elements
-                    .iter()
-                    .py_index(i32::try_from(int).expect("checked in branch arm"))
-                    .copied()
-                    .unwrap_or_else(|_| {
-                        self.diagnostics.add_index_out_of_bounds(
-                            "tuple",
-                            value_node.into(),
-                            value_ty,
-                            elements.len(),
-                            int,
-                        );
-                        Type::Unknown
-                    })
+                elements.iter().py_index(int).copied().unwrap_or_else(|_| {
+                    self.diagnostics.add_index_out_of_bounds(
+                        "tuple",
+                        value_node.into(),
+                        value_ty,
+                        elements.len(),
+                        int,
+                    );
+                    Type::Unknown
+                })
             }
             // Ex) Given `("a", 1, Null)[0:2]`, return `("a", 1)`
             (Type::Tuple(tuple_ty), Type::SliceLiteral(slice_ty)) => {
                 let elements = tuple_ty.elements(self.db);
                 let (start, stop, step) = slice_ty.as_tuple(self.db);
 
-                if let Ok(new_elements) = elements.py_slice(start, stop, step) {
-                    let new_elements: Vec<_> = new_elements.copied().collect();
+                if let Ok(new_elements) = elements.py_slice_owned(start, stop, step) {
+                    let new_elements: Vec<_> = new_elements.collect();
                     Type::tuple(self.db, &new_elements)
                 } else {
                     self.diagnostics.add_slice_step_size_zero(value_node.into());
@@ -3858,13 +5266,11 @@ impl<'db> TypeInferenceBuilder<'db> {
                 }
             }
             // Ex) Given `"value"[1]`, return `"a"`
-            (Type::StringLiteral(literal_ty), Type::IntLiteral(int))
-                if i32::try_from(int).is_ok() =>
-            {
+            (Type::StringLiteral(literal_ty), Type::IntLiteral(int)) => {
                 let literal_value = literal_ty.value(self.db);
                 literal_value
                     .chars()
-                    .py_index(i32::try_from(int).expect("checked in branch arm"))
+                    .py_index(int)
                     .map(|ch| Type::string_literal(self.db, &ch.to_string()))
                     .unwrap_or_else(|_| {
                         self.diagnostics.add_index_out_of_bounds(
@@ -3893,13 +5299,11 @@ impl<'db> TypeInferenceBuilder<'db> {
                 result
             }
             // Ex) Given `b"value"[1]`, return `b"a"`
-            (Type::BytesLiteral(literal_ty), Type::IntLiteral(int))
-                if i32::try_from(int).is_ok() =>
-            {
+            (Type::BytesLiteral(literal_ty), Type::IntLiteral(int)) => {
                 let literal_value = literal_ty.value(self.db);
                 literal_value
                     .iter()
-                    .py_index(i32::try_from(int).expect("checked in branch arm"))
+                    .py_index(int)
                     .map(|byte| Type::bytes_literal(self.db, &[*byte]))
                     .unwrap_or_else(|_| {
                         self.diagnostics.add_index_out_of_bounds(
@@ -3917,8 +5321,8 @@ impl<'db> TypeInferenceBuilder<'db> {
                 let literal_value = literal_ty.value(self.db);
                 let (start, stop, step) = slice_ty.as_tuple(self.db);
 
-                if let Ok(new_bytes) = literal_value.py_slice(start, stop, step) {
-                    let new_bytes: Vec<u8> = new_bytes.copied().collect();
+                if let Ok(new_bytes) = literal_value.py_slice_owned(start, stop, step) {
+                    let new_bytes: Vec<u8> = new_bytes.collect();
                     Type::bytes_literal(self.db, &new_bytes)
                 } else {
                     self.diagnostics.add_slice_step_size_zero(value_node.into());
@@ -3947,7 +5351,7 @@ impl<'db> TypeInferenceBuilder<'db> {
                         if boundness == Boundness::PossiblyUnbound {
                             self.diagnostics.add(
                                 value_node.into(),
-                                "call-possibly-unbound-method",
+                                Rule::CallPossiblyUnboundMethod,
                                 format_args!(
                                     "Method `__getitem__` of type `{}` is possibly unbound",
                                     value_ty.display(self.db),
@@ -3956,12 +5360,12 @@ impl<'db> TypeInferenceBuilder<'db> {
                         }
 
                         return dunder_getitem_method
-                            .call(self.db, &[slice_ty])
+                            .call(self.db, &CallArguments::positional([slice_ty]))
                             .return_ty_result(self.db, value_node.into(), &mut self.diagnostics)
                             .unwrap_or_else(|err| {
                                 self.diagnostics.add(
                                     value_node.into(),
-                                    "call-non-callable",
+                                    Rule::CallNonCallable,
                                     format_args!(
                                         "Method `__getitem__` of type `{}` is not callable on object of type `{}`",
                                         err.called_ty().display(self.db),
@@ -3991,7 +5395,7 @@ impl<'db> TypeInferenceBuilder<'db> {
                             if boundness == Boundness::PossiblyUnbound {
                                 self.diagnostics.add(
                                     value_node.into(),
-                                    "call-possibly-unbound-method",
+                                    Rule::CallPossiblyUnboundMethod,
                                     format_args!(
                                         "Method `__class_getitem__` of type `{}` is possibly unbound",
                                         value_ty.display(self.db),
@@ -4000,12 +5404,12 @@ impl<'db> TypeInferenceBuilder<'db> {
                             }
 
                             return ty
-                                .call(self.db, &[slice_ty])
+                                .call(self.db, &CallArguments::positional([slice_ty]))
                                 .return_ty_result(self.db, value_node.into(), &mut self.diagnostics)
                                 .unwrap_or_else(|err| {
                                     self.diagnostics.add(
                                         value_node.into(),
-                                        "call-non-callable",
+                                        Rule::CallNonCallable,
                                         format_args!(
                                             "Method `__class_getitem__` of type `{}` is not callable on object of type `{}`",
                                             err.called_ty().display(self.db),
@@ -4147,7 +5551,7 @@ impl<'db> TypeInferenceBuilder<'db> {
             ast::Expr::BytesLiteral(bytes) => {
                 self.diagnostics.add(
                     bytes.into(),
-                    "annotation-byte-string",
+                    Rule::AnnotationByteString,
                     format_args!("Type expressions cannot use bytes literal"),
                 );
                 Type::Unknown
@@ -4156,7 +5560,7 @@ impl<'db> TypeInferenceBuilder<'db> {
             ast::Expr::FString(fstring) => {
                 self.diagnostics.add(
                     fstring.into(),
-                    "annotation-f-string",
+                    Rule::AnnotationFString,
                     format_args!("Type expressions cannot use f-strings"),
                 );
                 Type::Unknown
@@ -4286,6 +5690,24 @@ impl<'db> TypeInferenceBuilder<'db> {
                 match binary.op {
                     // PEP-604 unions are okay, e.g., `int | str`
                     ast::Operator::BitOr => {
+                        // Before Python 3.10 the runtime can't evaluate `X | Y` in an
+                        // annotation. String annotations and annotations that are never
+                        // evaluated (stub files, `from __future__ import annotations`)
+                        // are fine on any version.
+                        let target_version = Program::get(self.db).target_version(self.db);
+                        if target_version < PythonVersion::PY310
+                            && !self.are_all_types_deferred()
+                            && !self.deferred_state.in_string_annotation()
+                        {
+                            self.diagnostics.add(
+                                expression.into(),
+                                Rule::UnionSyntaxNotAvailable,
+                                format_args!(
+                                    "PEP 604 union syntax (`X | Y`) in annotations requires \
+                                     Python 3.10 or newer (target version is {target_version})"
+                                ),
+                            );
+                        }
                         let left_ty = self.infer_type_expression(&binary.left);
                         let right_ty = self.infer_type_expression(&binary.right);
                         UnionType::from_elements(self.db, [left_ty, right_ty])
@@ -4497,6 +5919,19 @@ impl<'db> TypeInferenceBuilder<'db> {
             Type::KnownInstance(known_instance) => {
                 self.infer_parameterized_known_instance_type_expression(known_instance, slice)
             }
+            Type::ClassLiteral(ClassLiteralType { class })
+                if matches!(
+                    class.known(self.db),
+                    Some(
+                        KnownClass::List
+                            | KnownClass::Set
+                            | KnownClass::FrozenSet
+                            | KnownClass::Dict
+                    )
+                ) =>
+            {
+                self.infer_generic_class_type_expression(class, slice)
+            }
             _ => {
                 self.infer_type_expression(slice);
                 Type::Todo // TODO: generics
@@ -4504,6 +5939,35 @@ impl<'db> TypeInferenceBuilder<'db> {
         }
     }
 
+    /// Infer the type of a subscription of one of the builtin container classes in a type
+    /// expression, e.g. `list[int]` or `dict[str, int]`.
+    fn infer_generic_class_type_expression(
+        &mut self,
+        class: Class<'db>,
+        arguments: &ast::Expr,
+    ) -> Type<'db> {
+        let specialization: Box<[Type<'db>]> = match arguments {
+            ast::Expr::Tuple(tuple) => tuple
+                .elts
+                .iter()
+                .map(|element| self.infer_type_expression(element))
+                .collect(),
+            _ => Box::from([self.infer_type_expression(arguments)]),
+        };
+
+        let expected_arity = if class.is_known(self.db, KnownClass::Dict) {
+            2
+        } else {
+            1
+        };
+        if specialization.len() != expected_arity {
+            // TODO: emit a diagnostic for an incorrect number of type arguments
+            return Type::Unknown;
+        }
+
+        Type::GenericInstance(GenericInstanceType::new(self.db, class, specialization))
+    }
+
     fn infer_parameterized_known_instance_type_expression(
         &mut self,
         known_instance: KnownInstanceType,
@@ -4516,7 +5980,7 @@ impl<'db> TypeInferenceBuilder<'db> {
                     for node in nodes {
                         self.diagnostics.add(
                             node.into(),
-                            "invalid-literal-parameter",
+                            Rule::InvalidLiteralParameter,
                             format_args!(
                                 "Type arguments for `Literal` must be `None`, \
                                     a literal value (int, bool, str, or bytes), or an enum value"
@@ -4526,7 +5990,144 @@ impl<'db> TypeInferenceBuilder<'db> {
                     Type::Unknown
                 }
             },
+            // TODO: subscripted `Protocol[T]`
+            KnownInstanceType::Protocol => Type::Todo,
+            KnownInstanceType::Callable => self.infer_callable_type_expression(parameters),
+            KnownInstanceType::TypeGuard => Type::TypeGuard(TypeGuardType::new(
+                self.db,
+                TypeGuardKind::TypeGuard,
+                self.infer_type_expression(parameters),
+            )),
+            KnownInstanceType::TypeIs => Type::TypeGuard(TypeGuardType::new(
+                self.db,
+                TypeGuardKind::TypeIs,
+                self.infer_type_expression(parameters),
+            )),
+            // `TypedDict` is not subscriptable at runtime.
+            KnownInstanceType::TypedDict => Type::Todo,
+            KnownInstanceType::Optional => match parameters {
+                ast::Expr::Tuple(_) => {
+                    self.infer_type_expression(parameters);
+                    self.diagnostics.add(
+                        parameters.into(),
+                        Rule::InvalidTypeParameter,
+                        format_args!("`typing.Optional` requires exactly one type argument"),
+                    );
+                    Type::Unknown
+                }
+                _ => {
+                    let ty = self.infer_type_expression(parameters);
+                    UnionType::from_elements(self.db, [ty, Type::none(self.db)])
+                }
+            },
+            KnownInstanceType::Union => match parameters {
+                ast::Expr::Tuple(tuple) if tuple.is_empty() => {
+                    self.diagnostics.add(
+                        parameters.into(),
+                        Rule::InvalidTypeParameter,
+                        format_args!("`typing.Union` requires at least one type argument"),
+                    );
+                    Type::Unknown
+                }
+                ast::Expr::Tuple(tuple) => {
+                    let mut builder = UnionBuilder::new(self.db);
+                    for element in tuple {
+                        builder = builder.add(self.infer_type_expression(element));
+                    }
+                    builder.build()
+                }
+                // `Union[int]` is just `int`.
+                _ => self.infer_type_expression(parameters),
+            },
+            KnownInstanceType::Annotated => match parameters {
+                ast::Expr::Tuple(tuple) if tuple.len() >= 2 => {
+                    let ty = self.infer_type_expression(&tuple.elts[0]);
+                    // The remaining arguments are arbitrary metadata; they are ordinary
+                    // expressions, not type expressions, and don't affect the type.
+                    for metadata in &tuple.elts[1..] {
+                        self.infer_expression(metadata);
+                    }
+                    ty
+                }
+                _ => {
+                    self.diagnostics.add(
+                        parameters.into(),
+                        Rule::InvalidTypeParameter,
+                        format_args!(
+                            "`typing.Annotated` requires at least two arguments: \
+                             a type and its metadata"
+                        ),
+                    );
+                    // The intent is still clear, so use the lone argument as the type.
+                    self.infer_type_expression(parameters)
+                }
+            },
             KnownInstanceType::TypeVar(_) => Type::Todo,
+            KnownInstanceType::ParamSpec(_) => Type::Todo,
+        }
+    }
+
+    /// Given the slice of a `Callable[]` annotation, return the type that the annotation
+    /// represents.
+    fn infer_callable_type_expression(&mut self, parameters: &ast::Expr) -> Type<'db> {
+        // The subscript of `Callable` is a two-element tuple: a list of parameter types (or
+        // `...` for an arbitrary parameter list), followed by the return type.
+        let arguments = if let ast::Expr::Tuple(ast::ExprTuple { elts, .. }) = parameters {
+            &elts[..]
+        } else {
+            std::slice::from_ref(parameters)
+        };
+        let [parameter_list, return_expr] = arguments else {
+            for argument in arguments {
+                self.infer_type_expression(argument);
+            }
+            self.diagnostics.add(
+                parameters.into(),
+                Rule::InvalidCallableParameter,
+                format_args!(
+                    "Type arguments for `Callable` must be a list of parameter types \
+                     (or `...`) and a return type"
+                ),
+            );
+            return Type::Unknown;
+        };
+        match parameter_list {
+            ast::Expr::List(ast::ExprList { elts, .. }) => {
+                let parameter_types: Box<[Type<'db>]> = elts
+                    .iter()
+                    .map(|element| self.infer_type_expression(element))
+                    .collect();
+                let return_ty = self.infer_type_expression(return_expr);
+                Type::Callable(CallableType::new(
+                    self.db,
+                    Some(parameter_types),
+                    return_ty,
+                    None,
+                ))
+            }
+            // `Callable[..., T]` has a gradual parameter list: it accepts a call with
+            // any arguments.
+            ast::Expr::EllipsisLiteral(_) => {
+                let return_ty = self.infer_type_expression(return_expr);
+                Type::Callable(CallableType::new(self.db, None, return_ty, None))
+            }
+            // TODO: `Callable[Concatenate[...], T]`
+            _ => {
+                let parameter_list_ty = self.infer_type_expression(parameter_list);
+                let return_ty = self.infer_type_expression(return_expr);
+                if let Type::KnownInstance(KnownInstanceType::ParamSpec(param_spec)) =
+                    parameter_list_ty
+                {
+                    Type::Callable(CallableType::new(
+                        self.db,
+                        None,
+                        return_ty,
+                        Some(param_spec),
+                    ))
+                } else {
+                    Type::Todo
+                }
+            }
         }
     }
 
@@ -4725,6 +6326,19 @@ struct CompareUnsupportedError<'db> {
     right_ty: Type<'db>,
 }
 
+/// Render a chain of attribute accesses rooted at a name (`config.server`) the way it was
+/// written in the source, for use in diagnostics. Returns `None` if `expr` is anything other
+/// than a simple dotted chain.
+fn attribute_chain(expr: &ast::Expr) -> Option<String> {
+    match expr {
+        ast::Expr::Name(ast::ExprName { id, .. }) => Some(id.to_string()),
+        ast::Expr::Attribute(ast::ExprAttribute { value, attr, .. }) => {
+            Some(format!("{}.{}", attribute_chain(value)?, attr.id))
+        }
+        _ => None,
+    }
+}
+
 fn format_import_from_module(level: u32, module: Option<&str>) -> String {
     format!(
         "{}{}",
@@ -4751,7 +6365,8 @@ enum ModuleNameResolutionError {
 }
 
 /// Struct collecting string parts when inferring a formatted string. Infers a string literal if the
-/// concatenated string is small enough, otherwise infers a literal string.
+/// concatenated string is small enough, otherwise infers a literal string. Interpolations that are
+/// themselves `LiteralString` also demote the result to a literal string.
 ///
 /// If the formatted string contains an expression (with a representation unknown at compile time),
 /// infers an instance of `builtins.str`.
@@ -4787,6 +6402,12 @@ impl StringPartsCollector {
         self.expression = true;
     }
 
+    /// Add an interpolated part whose text is unknown at compile time but is itself some
+    /// literal string, so the concatenation is still a `LiteralString`.
+    fn add_literal_string(&mut self) {
+        self.concatenated = None;
+    }
+
     fn ty(self, db: &dyn Db) -> Type {
         if self.expression {
             KnownClass::Str.to_instance(db)
@@ -4810,19 +6431,51 @@ fn perform_rich_comparison<'db>(
 ) -> Result<Type<'db>, CompareUnsupportedError<'db>> {
     // The following resource has details about the rich comparison algorithm:
     // https://snarky.ca/unravelling-rich-comparison-operators/
-    //
-    // TODO: this currently gives the return type even if the arg types are invalid
-    // (e.g. int.__lt__ with string instance should be errored, currently bool)
 
     let call_dunder = |op: RichCompareOperator,
                        left: InstanceType<'db>,
                        right: InstanceType<'db>| {
-        match left.class.class_member(db, op.dunder()) {
-            Symbol::Type(class_member_dunder, Boundness::Bound) => class_member_dunder
-                .call(db, &[Type::Instance(left), Type::Instance(right)])
-                .return_ty(db),
-            _ => None,
+        let mut member = left.class.class_member(db, op.dunder());
+        // `@functools.total_ordering` synthesizes the missing ordering dunders at runtime
+        // by delegating to whichever one the class defines.
+        if !matches!(member, Symbol::Type(_, Boundness::Bound))
+            && !matches!(op, RichCompareOperator::Eq | RichCompareOperator::Ne)
+            && left.class.is_total_ordering(db)
+        {
+            member = ["__lt__", "__le__", "__gt__", "__ge__"]
+                .iter()
+                .map(|dunder| left.class.class_member(db, dunder))
+                .find(|member| matches!(member, Symbol::Type(_, Boundness::Bound)))
+                .unwrap_or(Symbol::Unbound);
         }
+        let Symbol::Type(class_member_dunder, Boundness::Bound) = member else {
+            return None;
+        };
+        // A dunder whose `other` parameter annotation rejects the right-hand side behaves
+        // as if it returned `NotImplemented`, giving the reflected operation its chance.
+        // Typeshed annotations rely on numeric promotions (an `int` argument is acceptable
+        // wherever a `float` is expected) that we don't model yet, so stub-defined dunders
+        // are exempt.
+        if let Type::FunctionLiteral(function) = class_member_dunder {
+            if !function.body_scope(db).file(db).is_stub(db.upcast()) {
+                if let Some(parameter_ty) =
+                    function.signature(db).positional_parameter_types().get(1)
+                {
+                    // TODO: a `TypeVar` parameter (generic dunder) can't be checked yet.
+                    if !matches!(parameter_ty, Type::KnownInstance(_))
+                        && !Type::Instance(right).is_assignable_to(db, *parameter_ty)
+                    {
+                        return None;
+                    }
+                }
+            }
+        }
+        class_member_dunder
+            .call(
+                db,
+                &CallArguments::positional([Type::Instance(left), Type::Instance(right)]),
+            )
+            .return_ty(db)
     };
 
     // The reflected dunder has priority if the right-hand side is a strict subclass of the left-hand side.
@@ -4863,7 +6516,10 @@ fn perform_membership_test_comparison<'db>(
         Symbol::Type(contains_dunder, Boundness::Bound) => {
             // If `__contains__` is available, it is used directly for the membership test.
             contains_dunder
-                .call(db, &[Type::Instance(right), Type::Instance(left)])
+                .call(
+                    db,
+                    &CallArguments::positional([Type::Instance(right), Type::Instance(left)]),
+                )
                 .return_ty(db)
         }
         _ => {
@@ -4871,6 +6527,7 @@ fn perform_membership_test_comparison<'db>(
             match Type::Instance(right).iterate(db) {
                 IterationOutcome::Iterable { .. } => Some(KnownClass::Bool.to_instance(db)),
                 IterationOutcome::NotIterable { .. }
+                | IterationOutcome::NotAsyncIterable { .. }
                 | IterationOutcome::PossiblyUnboundDunderIter { .. } => None,
             }
         }
@@ -4904,7 +6561,7 @@ mod tests {
     use crate::semantic_index::definition::Definition;
     use crate::semantic_index::symbol::FileScopeId;
     use crate::semantic_index::{global_scope, semantic_index, symbol_table, use_def_map};
-    use crate::types::check_types;
+    use crate::types::{check_types, MroResolution};
     use crate::{HasTy, ProgramSettings, SemanticModel};
     use ruff_db::files::{system_path_to_file, File};
     use ruff_db::parsed::parsed_module;
@@ -4914,6 +6571,31 @@ mod tests {
     use super::*;
 
     fn setup_db() -> TestDb {
+        setup_db_with_target_version(PythonVersion::default())
+    }
+
+    fn setup_db_with_target_version(target_version: PythonVersion) -> TestDb {
+        let db = TestDb::new();
+
+        let src_root = SystemPathBuf::from("/src");
+        db.memory_file_system()
+            .create_directory_all(&src_root)
+            .unwrap();
+
+        Program::from_settings(
+            &db,
+            &ProgramSettings {
+                target_version,
+                respect_noqa: false,
+                search_paths: SearchPathSettings::new(src_root),
+            },
+        )
+        .expect("Valid search path settings");
+
+        db
+    }
+
+    fn setup_db_with_respect_noqa() -> TestDb {
         let db = TestDb::new();
 
         let src_root = SystemPathBuf::from("/src");
@@ -4925,6 +6607,7 @@ mod tests {
             &db,
             &ProgramSettings {
                 target_version: PythonVersion::default(),
+                respect_noqa: true,
                 search_paths: SearchPathSettings::new(src_root),
             },
         )
@@ -4947,6 +6630,7 @@ mod tests {
             &db,
             &ProgramSettings {
                 target_version: PythonVersion::default(),
+                respect_noqa: false,
                 search_paths: SearchPathSettings {
                     custom_typeshed: Some(SystemPathBuf::from(typeshed)),
                     ..SearchPathSettings::new(src_root)
@@ -5023,6 +6707,104 @@ mod tests {
         assert_diagnostic_messages(diagnostics, expected);
     }
 
+    #[test]
+    fn noqa_suppresses_only_when_enabled() -> anyhow::Result<()> {
+        let source = "x: int = \"a\"  # noqa\n";
+
+        let mut db = setup_db();
+        db.write_file("src/a.py", source)?;
+        assert_file_diagnostics(
+            &db,
+            "src/a.py",
+            &["Object of type `Literal[\"a\"]` is not assignable to `int`"],
+        );
+
+        let mut db = setup_db_with_respect_noqa();
+        db.write_file("src/a.py", source)?;
+        assert_file_diagnostics(&db, "src/a.py", &[]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn noqa_with_codes() -> anyhow::Result<()> {
+        let mut db = setup_db_with_respect_noqa();
+
+        // A matching rule name suppresses the diagnostic; unknown codes are assumed to
+        // belong to the linter and are neither honored nor reported.
+        db.write_file(
+            "src/a.py",
+            "x: int = \"a\"  # noqa: E501, invalid-assignment\n",
+        )?;
+        assert_file_diagnostics(&db, "src/a.py", &[]);
+
+        db.write_file("src/b.py", "x: int = \"a\"  # noqa: E501\n")?;
+        assert_file_diagnostics(
+            &db,
+            "src/b.py",
+            &["Object of type `Literal[\"a\"]` is not assignable to `int`"],
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn type_too_complex_diagnostic_points_at_binding() -> anyhow::Result<()> {
+        let mut db = setup_db();
+
+        // A union of 301 single-element tuple types has structural size 603,
+        // which is above `TYPE_COMPLEXITY_LIMIT`.
+        use std::fmt::Write as _;
+
+        let mut source = String::from("def f(flag: bool):\n    x = ");
+        for i in 0..300 {
+            write!(source, "({i},) if flag else ").unwrap();
+        }
+        source.push_str("(300,)\n");
+        db.write_file("src/a.py", source)?;
+
+        assert_file_diagnostics(
+            &db,
+            "src/a.py",
+            &[
+                "Inferred type of this binding has structural size 603, which exceeds the \
+                 complexity limit (256); it will be summarized in diagnostics",
+            ],
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn call_non_callable_union_element_diagnostic_underlines_branch() -> anyhow::Result<()> {
+        let mut db = setup_db();
+
+        let source = "\
+def f() -> int: ...
+
+def g(flag: bool):
+    x = (f if flag else 1)()
+";
+        db.write_file("src/a.py", source)?;
+
+        let file = system_path_to_file(&db, "src/a.py").unwrap();
+        let diagnostics = check_types(&db, file);
+        assert_diagnostic_messages(
+            diagnostics,
+            &["Object of type `Literal[f] | Literal[1]` is not callable \
+               (due to union element `Literal[1]`)"],
+        );
+
+        // The diagnostic is anchored on the non-callable branch of the callee, not on
+        // the whole callee.
+        let start = u32::try_from(source.find("1)()").unwrap()).unwrap();
+        assert_eq!(
+            diagnostics[0].range(),
+            TextRange::new(start.into(), (start + 1).into())
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn from_import_with_no_module_name() -> anyhow::Result<()> {
         // This test checks that invalid syntax in a `StmtImportFrom` node
@@ -5033,6 +6815,84 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn stub_version_guarded_declarations_use_selected_branch() -> anyhow::Result<()> {
+        let source = "
+            import sys
+
+            if sys.version_info >= (3, 10):
+                x: int
+            else:
+                x: str
+            ";
+
+        for (target_version, expected) in
+            [(PythonVersion::PY38, "str"), (PythonVersion::PY311, "int")]
+        {
+            let mut db = setup_db_with_target_version(target_version);
+            db.write_dedented("src/mod.pyi", source)?;
+            assert_public_ty(&db, "src/mod.pyi", "x", expected);
+        }
+
+        // In a runtime file the guard is not evaluated statically, and the public type
+        // remains the union of both branches.
+        let mut db = setup_db_with_target_version(PythonVersion::PY38);
+        db.write_dedented("src/mod.py", source)?;
+        assert_public_ty(&db, "src/mod.py", "x", "int | str");
+
+        Ok(())
+    }
+
+    #[test]
+    fn stub_conditionally_defined_symbol_is_not_possibly_unbound() -> anyhow::Result<()> {
+        let mut db = setup_db();
+
+        db.write_dedented(
+            "src/mod.pyi",
+            "
+            import sys
+
+            if sys.platform == 'win32':
+                x: int
+            ",
+        )?;
+
+        let file = system_path_to_file(&db, "src/mod.pyi").unwrap();
+        let symbol = global_symbol(&db, file, "x");
+        assert!(!symbol.possibly_unbound());
+        assert_public_ty(&db, "src/mod.pyi", "x", "int");
+
+        Ok(())
+    }
+
+    #[test]
+    fn stub_last_definition_wins_for_unevaluated_guards() -> anyhow::Result<()> {
+        let mut db = setup_db();
+
+        db.write_dedented(
+            "src/mod.pyi",
+            "
+            import sys
+
+            if sys.platform == 'win32':
+                def f() -> int: ...
+            else:
+                def f() -> str: ...
+            ",
+        )?;
+
+        // Not a union of both branches: the textually last live definition is the
+        // visible one.
+        let file = system_path_to_file(&db, "src/mod.pyi").unwrap();
+        let function = global_symbol(&db, file, "f")
+            .expect_type()
+            .expect_function_literal();
+        let return_ty = function.signature(&db).return_ty;
+        assert_eq!(return_ty.display(&db).to_string(), "str");
+
+        Ok(())
+    }
+
     #[test]
     fn resolve_method() -> anyhow::Result<()> {
         let mut db = setup_db();
@@ -5204,6 +7064,63 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn multiplied_bytes() -> anyhow::Result<()> {
+        let mut db = setup_db();
+
+        db.write_dedented(
+            "src/a.py",
+            &format!(
+                r#"
+            w = 2 * b"hello"
+            x = b"goodbye" * 3
+            y = b"a" * {y}
+            z = {z} * b"b"
+            a = 0 * b"hello"
+            b = -3 * b"hello"
+            "#,
+                y = TypeInferenceBuilder::MAX_STRING_LITERAL_SIZE,
+                z = TypeInferenceBuilder::MAX_STRING_LITERAL_SIZE + 1
+            ),
+        )?;
+
+        assert_public_ty(&db, "src/a.py", "w", r#"Literal[b"hellohello"]"#);
+        assert_public_ty(&db, "src/a.py", "x", r#"Literal[b"goodbyegoodbyegoodbye"]"#);
+        assert_public_ty(
+            &db,
+            "src/a.py",
+            "y",
+            &format!(
+                r#"Literal[b"{}"]"#,
+                "a".repeat(TypeInferenceBuilder::MAX_STRING_LITERAL_SIZE)
+            ),
+        );
+        assert_public_ty(&db, "src/a.py", "z", "bytes");
+        assert_public_ty(&db, "src/a.py", "a", r#"Literal[b""]"#);
+        assert_public_ty(&db, "src/a.py", "b", r#"Literal[b""]"#);
+
+        Ok(())
+    }
+
+    #[test]
+    fn truncated_bytes_literals_become_bytes_instances() -> anyhow::Result<()> {
+        let mut db = setup_db();
+        let content = format!(
+            r#"
+        w = b"{y}"
+        x = b"a" + b"{z}"
+        "#,
+            y = "a".repeat(TypeInferenceBuilder::MAX_STRING_LITERAL_SIZE + 1),
+            z = "a".repeat(TypeInferenceBuilder::MAX_STRING_LITERAL_SIZE),
+        );
+        db.write_dedented("src/a.py", &content)?;
+
+        assert_public_ty(&db, "src/a.py", "w", "bytes");
+        assert_public_ty(&db, "src/a.py", "x", "bytes");
+
+        Ok(())
+    }
+
     #[test]
     fn ellipsis_type() -> anyhow::Result<()> {
         let mut db = setup_db();
@@ -5256,6 +7173,92 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn public_mro_resolution() -> anyhow::Result<()> {
+        fn class_named<'db>(db: &'db TestDb, file: File, name: &str) -> Class<'db> {
+            global_symbol(db, file, name)
+                .expect_type()
+                .expect_class_literal()
+                .class
+        }
+
+        fn mro_names<'db>(db: &'db TestDb, resolution: &MroResolution<'db>) -> Vec<String> {
+            resolution
+                .types()
+                .iter()
+                .map(|ty| ty.display(db).to_string())
+                .collect()
+        }
+
+        let mut db = setup_db();
+
+        db.write_dedented(
+            "src/a.py",
+            "
+            def flag() -> bool: ...
+
+            class A: ...
+            class B: ...
+            class Left(A): ...
+            class Right(A): ...
+            class Diamond(Left, Right): ...
+            class Duplicate(A, A): ...
+
+            UnionBase = Left if flag() else Right
+            class FromUnion(UnionBase): ...
+            ",
+        )?;
+
+        let file = system_path_to_file(&db, "src/a.py").expect("file to exist");
+
+        // Single inheritance resolves to an ordered list.
+        let left = class_named(&db, file, "Left");
+        let MroResolution::Resolved(_) = left.mro(&db) else {
+            panic!("expected the MRO of `Left` to resolve");
+        };
+        assert_eq!(
+            mro_names(&db, &left.mro(&db)),
+            ["Literal[Left]", "Literal[A]", "Literal[object]"]
+        );
+
+        // Diamond inheritance linearizes via C3.
+        let diamond = class_named(&db, file, "Diamond");
+        assert_eq!(
+            mro_names(&db, &diamond.mro(&db)),
+            [
+                "Literal[Diamond]",
+                "Literal[Left]",
+                "Literal[Right]",
+                "Literal[A]",
+                "Literal[object]"
+            ]
+        );
+        let a = class_named(&db, file, "A");
+        let b = class_named(&db, file, "B");
+        assert!(diamond.is_subclass_of(&db, a));
+        assert!(!diamond.is_subclass_of(&db, b));
+
+        // Duplicate and union bases fall back to `[<class>, Unknown, object]`, and the
+        // fallback does not make the class a subclass of anything it names.
+        for name in ["Duplicate", "FromUnion"] {
+            let class = class_named(&db, file, name);
+            let MroResolution::Error(_) = class.mro(&db) else {
+                panic!("expected the MRO of `{name}` to be an error");
+            };
+            assert_eq!(
+                mro_names(&db, &class.mro(&db)),
+                [
+                    format!("Literal[{name}]"),
+                    "Unknown".to_string(),
+                    "Literal[object]".to_string()
+                ]
+            );
+            assert!(!class.is_subclass_of(&db, a));
+        }
+
+        Ok(())
+    }
+
     /// An unbound function local that has definitions in the scope does not fall back to globals.
     #[test]
     fn unbound_function_local() -> anyhow::Result<()> {
@@ -5699,7 +7702,11 @@ mod tests {
 
         assert_scope_ty(&db, "src/a.py", &["foo", "<listcomp>"], "x", "int");
         assert_scope_ty(&db, "src/a.py", &["foo", "<listcomp>"], "z", "Unknown");
-        assert_file_diagnostics(&db, "src/a.py", &["Object of type `int` is not iterable"]);
+        assert_file_diagnostics(
+            &db,
+            "src/a.py",
+            &["Object of type `int` is not iterable because it has no `__iter__` method and no `__getitem__` method"],
+        );
 
         Ok(())
     }
@@ -5904,7 +7911,7 @@ mod tests {
         assert_file_diagnostics(
             &db,
             "/src/a.py",
-            &["Object of type `NotIterable` is not iterable"],
+            &["Object of type `NotIterable` is not iterable because it has no `__iter__` method and no `__getitem__` method"],
         );
     }
 
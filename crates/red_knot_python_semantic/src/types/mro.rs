@@ -112,32 +112,33 @@ impl<'db> Mro<'db> {
                     return Err(MroErrorKind::InvalidBases(invalid_bases.into_boxed_slice()));
                 }
 
+                // A class listing the same base twice always fails the C3 merge; detect it
+                // upfront rather than feeding `c3_merge` degenerate input.
+                let mut seen_bases = FxHashSet::default();
+                let mut duplicate_bases = vec![];
+                for (index, base) in valid_bases
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(index, base)| Some((index, base.into_class_literal_type()?)))
+                {
+                    if !seen_bases.insert(base) {
+                        duplicate_bases.push((index, base));
+                    }
+                }
+                if !duplicate_bases.is_empty() {
+                    return Err(MroErrorKind::DuplicateBases(
+                        duplicate_bases.into_boxed_slice(),
+                    ));
+                }
+
                 let mut seqs = vec![VecDeque::from([ClassBase::Class(class)])];
                 for base in &valid_bases {
                     seqs.push(base.mro(db).collect());
                 }
                 seqs.push(valid_bases.iter().copied().collect());
 
-                c3_merge(seqs).ok_or_else(|| {
-                    let mut seen_bases = FxHashSet::default();
-                    let mut duplicate_bases = vec![];
-                    for (index, base) in valid_bases
-                        .iter()
-                        .enumerate()
-                        .filter_map(|(index, base)| Some((index, base.into_class_literal_type()?)))
-                    {
-                        if !seen_bases.insert(base) {
-                            duplicate_bases.push((index, base));
-                        }
-                    }
-
-                    if duplicate_bases.is_empty() {
-                        MroErrorKind::UnresolvableMro {
-                            bases_list: valid_bases.into_boxed_slice(),
-                        }
-                    } else {
-                        MroErrorKind::DuplicateBases(duplicate_bases.into_boxed_slice())
-                    }
+                c3_merge(seqs).ok_or_else(|| MroErrorKind::UnresolvableMro {
+                    bases_list: valid_bases.into_boxed_slice(),
                 })
             }
         }
@@ -359,6 +360,12 @@ impl<'db> ClassBase<'db> {
             Type::Union(_) => None, // TODO -- forces consideration of multiple possible MROs?
             Type::Intersection(_) => None, // TODO -- probably incorrect?
             Type::Instance(_) => None, // TODO -- handle `__mro_entries__`?
+            Type::GenericInstance(_) => None,
+            Type::Iterator(_) => None,
+            Type::Coroutine(_) => None,
+            Type::BoundSuper(_) => None,
+            Type::Callable(_) => None,
+            Type::TypeGuard(_) => None,
             Type::Never
             | Type::BooleanLiteral(_)
             | Type::FunctionLiteral(_)
@@ -372,7 +379,20 @@ impl<'db> ClassBase<'db> {
             | Type::SubclassOf(_) => None,
             Type::KnownInstance(known_instance) => match known_instance {
                 KnownInstanceType::Literal => None,
+                // TODO: we don't yet model the runtime `Protocol` and `Generic` classes that
+                // appear in the MRO of a protocol class.
+                KnownInstanceType::Protocol => Some(Self::Todo),
+                KnownInstanceType::Callable => None,
+                KnownInstanceType::TypeGuard => None,
+                KnownInstanceType::TypeIs => None,
+                // TODO: we don't yet model the runtime `_TypedDict` `Mapping` subclass that
+                // class-based `TypedDict`s actually inherit from.
+                KnownInstanceType::TypedDict => Some(Self::Todo),
+                KnownInstanceType::Optional => None,
+                KnownInstanceType::Union => None,
+                KnownInstanceType::Annotated => None,
                 KnownInstanceType::TypeVar(_) => None,
+                KnownInstanceType::ParamSpec(_) => None,
             },
         }
     }
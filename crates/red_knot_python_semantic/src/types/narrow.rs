@@ -4,9 +4,10 @@ use crate::semantic_index::definition::Definition;
 use crate::semantic_index::expression::Expression;
 use crate::semantic_index::symbol::{ScopeId, ScopedSymbolId, SymbolTable};
 use crate::semantic_index::symbol_table;
+use crate::symbol::{Boundness, Symbol};
 use crate::types::{
     infer_expression_types, ClassLiteralType, IntersectionBuilder, KnownClass,
-    KnownConstraintFunction, KnownFunction, Truthiness, Type, UnionBuilder,
+    KnownConstraintFunction, KnownFunction, Truthiness, Type, TypeGuardKind, UnionBuilder,
 };
 use crate::Db;
 use itertools::Itertools;
@@ -82,6 +83,20 @@ fn all_negative_narrowing_constraints_for_expression<'db>(
 ///
 /// The `classinfo` argument can be a class literal, a tuple of (tuples of) class literals. PEP 604
 /// union types are not yet supported. Returns `None` if the `classinfo` argument has a wrong type.
+/// Extract the name a narrowing constraint can be keyed on from a comparator expression:
+/// either a plain name, or the target of a walrus assignment as in
+/// `if (x := f()) is not None:`.
+fn narrowing_target(expr: &ast::Expr) -> Option<&ast::name::Name> {
+    match expr {
+        ast::Expr::Name(ast::ExprName { id, .. }) => Some(id),
+        ast::Expr::Named(ast::ExprNamed { target, .. }) => match &**target {
+            ast::Expr::Name(ast::ExprName { id, .. }) => Some(id),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
 fn generate_classinfo_constraint<'db, F>(
     db: &'db dyn Db,
     classinfo: &Type<'db>,
@@ -263,8 +278,12 @@ impl<'db> NarrowingConstraintsBuilder<'db> {
             ops,
             comparators,
         } = expr_compare;
-        if !left.is_name_expr() && comparators.iter().all(|c| !c.is_name_expr()) {
-            // If none of the comparators are name expressions,
+        if narrowing_target(left).is_none()
+            && comparators
+                .iter()
+                .all(|comparator| narrowing_target(comparator).is_none())
+        {
+            // If none of the comparators are (possibly walrus-bound) name expressions,
             // we have no symbol to narrow down the type of.
             return None;
         }
@@ -283,12 +302,7 @@ impl<'db> NarrowingConstraintsBuilder<'db> {
             .tuple_windows::<(&ruff_python_ast::Expr, &ruff_python_ast::Expr)>();
         let mut constraints = NarrowingConstraints::default();
         for (op, (left, right)) in std::iter::zip(&**ops, comparator_tuples) {
-            if let ast::Expr::Name(ast::ExprName {
-                range: _,
-                id,
-                ctx: _,
-            }) = left
-            {
+            if let Some(id) = narrowing_target(left) {
                 // SAFETY: we should always have a symbol for every Name node.
                 let symbol = self.symbols().symbol_id_by_name(id).unwrap();
                 let rhs_ty = inference.expression_ty(right.scoped_ast_id(self.db, scope));
@@ -315,10 +329,81 @@ impl<'db> NarrowingConstraintsBuilder<'db> {
                             constraints.insert(symbol, ty);
                         }
                     }
+                    // `x in (1, 2)` narrows `x` to one of the container's elements, but only
+                    // if every element is single-valued; an element like `int` could compare
+                    // equal to values outside the container. The same caveat gates the
+                    // negation: `x not in (1, 2)` excludes each single-valued element.
+                    //
+                    // Only tuples of known elements support this; `in` on a string tests for
+                    // *substrings*, so a match there doesn't pin `x` to one of the elements.
+                    ast::CmpOp::In => {
+                        if let Type::Tuple(tuple) = rhs_ty {
+                            let elements = tuple.elements(self.db);
+                            if elements
+                                .iter()
+                                .all(|element| element.is_single_valued(self.db))
+                            {
+                                let mut builder = UnionBuilder::new(self.db);
+                                for element in elements {
+                                    builder = builder.add(*element);
+                                }
+                                constraints.insert(symbol, builder.build());
+                            }
+                        }
+                    }
+                    ast::CmpOp::NotIn => {
+                        if let Type::Tuple(tuple) = rhs_ty {
+                            let elements = tuple.elements(self.db);
+                            if elements
+                                .iter()
+                                .all(|element| element.is_single_valued(self.db))
+                            {
+                                let mut builder = IntersectionBuilder::new(self.db);
+                                for element in elements {
+                                    builder = builder.add_negative(*element);
+                                }
+                                constraints.insert(symbol, builder.build());
+                            }
+                        }
+                    }
                     _ => {
                         // TODO other comparison types
                     }
                 }
+            } else if let ast::Expr::Call(ast::ExprCall {
+                range: _,
+                func: callable,
+                arguments,
+            }) = left
+            {
+                // `type(x) is C` narrows `x` to an instance of exactly `C` on the true
+                // branch. Unlike `isinstance`, subclasses of `C` are excluded, so the
+                // negated form cannot narrow at all: `x` might still be an instance of a
+                // subclass of `C`.
+                if !matches!(if is_positive { *op } else { op.negate() }, ast::CmpOp::Is) {
+                    continue;
+                }
+                let [ast::Expr::Name(ast::ExprName { id, .. })] = &*arguments.args else {
+                    continue;
+                };
+                if !arguments.keywords.is_empty() {
+                    continue;
+                }
+                let is_type_call = inference
+                    .expression_ty(callable.scoped_ast_id(self.db, scope))
+                    .into_class_literal()
+                    .is_some_and(|ClassLiteralType { class }| {
+                        class.is_known(self.db, KnownClass::Type)
+                    });
+                if !is_type_call {
+                    continue;
+                }
+                let rhs_ty = inference.expression_ty(right.scoped_ast_id(self.db, scope));
+                if let Type::ClassLiteral(ClassLiteralType { class }) = rhs_ty {
+                    // SAFETY: we should always have a symbol for every Name node.
+                    let symbol = self.symbols().symbol_id_by_name(id).unwrap();
+                    constraints.insert(symbol, Type::instance(class));
+                }
             }
         }
         Some(constraints)
@@ -332,15 +417,20 @@ impl<'db> NarrowingConstraintsBuilder<'db> {
     ) -> Option<NarrowingConstraints<'db>> {
         let scope = self.scope();
         let inference = infer_expression_types(self.db, expression);
+        let callable_ty =
+            inference.expression_ty(expr_call.func.scoped_ast_id(self.db, scope));
+
+        let known_function = callable_ty
+            .into_function_literal()
+            .and_then(|f| f.known(self.db));
+
+        if known_function == Some(KnownFunction::HasAttr) {
+            return self.evaluate_hasattr_call(expr_call, expression, is_positive);
+        }
 
         // TODO: add support for PEP 604 union types on the right hand side of `isinstance`
         // and `issubclass`, for example `isinstance(x, str | (int | float))`.
-        match inference
-            .expression_ty(expr_call.func.scoped_ast_id(self.db, scope))
-            .into_function_literal()
-            .and_then(|f| f.known(self.db))
-            .and_then(KnownFunction::constraint_function)
-        {
+        match known_function.and_then(KnownFunction::constraint_function) {
             Some(function) if expr_call.arguments.keywords.is_empty() => {
                 if let [ast::Expr::Name(ast::ExprName { id, .. }), class_info] =
                     &*expr_call.arguments.args
@@ -374,10 +464,106 @@ impl<'db> NarrowingConstraintsBuilder<'db> {
                     None
                 }
             }
-            _ => None,
+            _ => self.evaluate_type_guard_call(callable_ty, expr_call, is_positive),
         }
     }
 
+    /// Evaluate a call to `hasattr`: `if hasattr(x, "attr"):` narrows `x` so that looking up
+    /// `attr` on the narrowed type doesn't come back unbound.
+    ///
+    /// A union is filtered down to the elements that (possibly) have the attribute on the
+    /// true branch, and to those that (possibly) lack it on the false branch. For other
+    /// types we can't express "this type, but with an `attr` member" precisely, so the true
+    /// branch intersects with `Unknown` instead; member lookup on the intersection then no
+    /// longer reports the attribute as unresolved.
+    fn evaluate_hasattr_call(
+        &mut self,
+        expr_call: &ast::ExprCall,
+        expression: Expression<'db>,
+        is_positive: bool,
+    ) -> Option<NarrowingConstraints<'db>> {
+        if !expr_call.arguments.keywords.is_empty() {
+            return None;
+        }
+        let [target @ ast::Expr::Name(ast::ExprName { id, .. }), attribute] =
+            &*expr_call.arguments.args
+        else {
+            return None;
+        };
+
+        let scope = self.scope();
+        let inference = infer_expression_types(self.db, expression);
+
+        let Type::StringLiteral(attribute) =
+            inference.expression_ty(attribute.scoped_ast_id(self.db, scope))
+        else {
+            return None;
+        };
+        let attribute = attribute.value(self.db);
+        let target_ty = inference.expression_ty(target.scoped_ast_id(self.db, scope));
+
+        let constraint = match target_ty {
+            Type::Union(union) => {
+                let mut builder = UnionBuilder::new(self.db);
+                for element in union.elements(self.db) {
+                    let keep = match element.member(self.db, attribute) {
+                        Symbol::Unbound => !is_positive,
+                        Symbol::Type(_, Boundness::PossiblyUnbound) => true,
+                        Symbol::Type(_, Boundness::Bound) => is_positive,
+                    };
+                    if keep {
+                        builder = builder.add(*element);
+                    }
+                }
+                builder.build()
+            }
+            _ if is_positive && matches!(target_ty.member(self.db, attribute), Symbol::Unbound) => {
+                Type::Unknown
+            }
+            _ => return None,
+        };
+
+        // SAFETY: we should always have a symbol for every Name node.
+        let symbol = self.symbols().symbol_id_by_name(id).unwrap();
+        let mut constraints = NarrowingConstraints::default();
+        constraints.insert(symbol, constraint);
+        Some(constraints)
+    }
+
+    /// Evaluate a call to a user-defined type-guard function: `if guard(x):` narrows `x` to
+    /// `T` when `guard` is annotated to return `TypeGuard[T]` or `TypeIs[T]`.
+    fn evaluate_type_guard_call(
+        &mut self,
+        callable_ty: Type<'db>,
+        expr_call: &ast::ExprCall,
+        is_positive: bool,
+    ) -> Option<NarrowingConstraints<'db>> {
+        let function = callable_ty.into_function_literal()?;
+        let Type::TypeGuard(guard) = function.signature(self.db).return_ty else {
+            return None;
+        };
+        let [ast::Expr::Name(ast::ExprName { id, .. }), ..] = &*expr_call.arguments.args else {
+            return None;
+        };
+        let constraint = if is_positive {
+            guard.guarded_ty(self.db)
+        } else {
+            match guard.kind(self.db) {
+                TypeGuardKind::TypeIs => IntersectionBuilder::new(self.db)
+                    .add_negative(guard.guarded_ty(self.db))
+                    .build(),
+                // A `TypeGuard` function may return `False` even for values that do have the
+                // guarded type, so the negative branch learns nothing.
+                TypeGuardKind::TypeGuard => return None,
+            }
+        };
+        // SAFETY: we should always have a symbol for every Name node.
+        let symbol = self.symbols().symbol_id_by_name(id).unwrap();
+        let mut constraints = NarrowingConstraints::default();
+        constraints.insert(symbol, constraint);
+        Some(constraints)
+    }
+
     fn evaluate_match_pattern_singleton(
         &mut self,
         subject: &ast::Expr,
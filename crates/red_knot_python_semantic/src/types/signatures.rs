@@ -1,5 +1,5 @@
 #![allow(dead_code)]
-use super::{definition_expression_ty, Type};
+use super::{definition_expression_ty, ClassLiteralType, CoroutineType, KnownClass, Type};
 use crate::semantic_index::definition::Definition;
 use crate::Db;
 use ruff_python_ast::{self as ast, name::Name};
@@ -32,11 +32,23 @@ impl<'db> Signature<'db> {
             .returns
             .as_ref()
             .map(|returns| {
+                let annotated_ty = definition_expression_ty(db, definition, returns.as_ref());
                 if function_node.is_async {
-                    // TODO: generic `types.CoroutineType`!
-                    Type::Todo
+                    // The annotation on an `async` function describes the value wrapped in
+                    // the returned coroutine object.
+                    // TODO: model the yield and send types too (`Coroutine[Y, S, R]`) once
+                    // we support generics; for now only the awaited result type is tracked.
+                    match KnownClass::CoroutineType
+                        .to_class_literal(db)
+                        .into_class_literal()
+                    {
+                        Some(ClassLiteralType { class }) => {
+                            Type::Coroutine(CoroutineType::new(db, class, annotated_ty))
+                        }
+                        None => Type::Todo,
+                    }
                 } else {
-                    definition_expression_ty(db, definition, returns.as_ref())
+                    annotated_ty
                 }
             })
             .unwrap_or(Type::Unknown);
@@ -50,6 +62,41 @@ impl<'db> Signature<'db> {
             return_ty,
         }
     }
+
+    /// Annotated types of the parameters that can be filled by positional arguments, in order:
+    /// the positional-only parameters followed by the positional-or-keyword ones.
+    pub(crate) fn positional_parameter_types(&self) -> Vec<Type<'db>> {
+        self.parameters
+            .positional_only
+            .iter()
+            .chain(&self.parameters.positional_or_keyword)
+            .map(|parameter_with_default| parameter_with_default.parameter.annotated_ty)
+            .collect()
+    }
+
+    /// Annotated type of the parameter that a keyword argument `name` binds to, or `None` if no
+    /// parameter can be addressed by that name. Positional-only parameters and the variadic
+    /// parameters are never addressable by name.
+    pub(crate) fn keyword_parameter_ty(&self, name: &str) -> Option<Type<'db>> {
+        self.parameters
+            .positional_or_keyword
+            .iter()
+            .chain(&self.parameters.keyword_only)
+            .find(|parameter_with_default| {
+                parameter_with_default
+                    .parameter
+                    .name
+                    .as_ref()
+                    .is_some_and(|parameter_name| parameter_name.as_str() == name)
+            })
+            .map(|parameter_with_default| parameter_with_default.parameter.annotated_ty)
+    }
+
+    /// Return `true` if this signature has a `**kwargs` parameter, which accepts keyword
+    /// arguments of any name.
+    pub(crate) fn has_variadic_keywords(&self) -> bool {
+        self.parameters.keywords.is_some()
+    }
 }
 
 /// The parameters portion of a typed signature.
@@ -209,6 +256,7 @@ mod tests {
             &db,
             &ProgramSettings {
                 target_version: PythonVersion::default(),
+                respect_noqa: false,
                 search_paths: SearchPathSettings::new(src_root),
             },
         )
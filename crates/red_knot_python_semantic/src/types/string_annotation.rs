@@ -5,7 +5,7 @@ use ruff_python_ast::{self as ast, ModExpression, StringFlags};
 use ruff_python_parser::{parse_expression_range, Parsed};
 use ruff_text_size::Ranged;
 
-use crate::types::diagnostic::{TypeCheckDiagnostics, TypeCheckDiagnosticsBuilder};
+use crate::types::diagnostic::{Rule, TypeCheckDiagnostics, TypeCheckDiagnosticsBuilder};
 use crate::Db;
 
 type AnnotationParseResult = Result<Parsed<ModExpression>, TypeCheckDiagnostics>;
@@ -27,7 +27,7 @@ pub(crate) fn parse_string_annotation(
         if prefix.is_raw() {
             diagnostics.add(
                 string_literal.into(),
-                "annotation-raw-string",
+                Rule::AnnotationRawString,
                 format_args!("Type expressions cannot use raw string literal"),
             );
         // Compare the raw contents (without quotes) of the expression with the parsed contents
@@ -51,7 +51,7 @@ pub(crate) fn parse_string_annotation(
                 Ok(parsed) => return Ok(parsed),
                 Err(parse_error) => diagnostics.add(
                     string_literal.into(),
-                    "forward-annotation-syntax-error",
+                    Rule::ForwardAnnotationSyntaxError,
                     format_args!("Syntax error in forward annotation: {}", parse_error.error),
                 ),
             }
@@ -60,7 +60,7 @@ pub(crate) fn parse_string_annotation(
             // case for annotations that contain escape sequences.
             diagnostics.add(
                 string_expr.into(),
-                "annotation-escape-character",
+                Rule::AnnotationEscapeCharacter,
                 format_args!("Type expressions cannot contain escape characters"),
             );
         }
@@ -68,7 +68,7 @@ pub(crate) fn parse_string_annotation(
         // String is implicitly concatenated.
         diagnostics.add(
             string_expr.into(),
-            "annotation-implicit-concat",
+            Rule::AnnotationImplicitConcat,
             format_args!("Type expressions cannot span multiple string literals"),
         );
     }
@@ -0,0 +1,225 @@
+use ruff_db::files::File;
+use ruff_db::parsed::parsed_module;
+use ruff_db::source::{line_index, source_text};
+use ruff_python_parser::TokenKind;
+use ruff_source_file::OneIndexed;
+use ruff_text_size::{Ranged, TextRange, TextSize};
+
+use crate::types::diagnostic::Rule;
+use crate::types::{TypeCheckDiagnostic, TypeCheckDiagnostics};
+use crate::{Db, Program};
+
+/// Removes diagnostics that are suppressed by a `# type: ignore`, `# knot: ignore`, or
+/// (when [`Program::respect_noqa`] is enabled) `# noqa` comment on the same line, and
+/// emits an `unused-ignore` diagnostic for every suppression comment that doesn't
+/// suppress anything.
+///
+/// A bare suppression comment suppresses all diagnostics on its line; a comment with
+/// codes (`# type: ignore[possibly-unresolved-reference]`, `# noqa: unresolved-reference`)
+/// only suppresses the listed rules. Since `noqa` comments are shared with the linter,
+/// codes we don't recognize in them are assumed to belong to the linter and are ignored,
+/// and a `noqa` that suppresses no type check diagnostic is never reported as unused.
+/// In a `knot: ignore` comment, by contrast, every code must name a known rule; unknown
+/// codes (e.g. linter rule codes) are reported under `unknown-rule`.
+pub(super) fn check_suppressions(db: &dyn Db, file: File, diagnostics: &mut TypeCheckDiagnostics) {
+    let source = source_text(db.upcast(), file);
+    let index = line_index(db.upcast(), file);
+    let respect_noqa = Program::get(db).respect_noqa(db);
+
+    let mut suppressions: Vec<Suppression> = parsed_module(db.upcast(), file)
+        .tokens()
+        .iter()
+        .filter(|token| token.kind() == TokenKind::Comment)
+        .filter_map(|token| {
+            let (kind, codes) = parse_suppression_comment(&source[token.range()])?;
+            if kind == SuppressionKind::Noqa && !respect_noqa {
+                return None;
+            }
+            Some(Suppression {
+                kind,
+                codes: codes
+                    .into_iter()
+                    .map(|(code, range)| (code, range + token.start()))
+                    .collect(),
+                line: index.line_index(token.start()),
+                range: token.range(),
+                used: false,
+            })
+        })
+        .collect();
+
+    if suppressions.is_empty() {
+        return;
+    }
+
+    diagnostics.retain(|diagnostic| {
+        let line = index.line_index(diagnostic.start());
+        let mut suppressed = false;
+
+        for suppression in &mut suppressions {
+            if suppression.line == line && suppression.suppresses(diagnostic.rule()) {
+                suppression.used = true;
+                suppressed = true;
+            }
+        }
+
+        !suppressed
+    });
+
+    if !db.is_file_open(file) {
+        return;
+    }
+
+    for suppression in suppressions {
+        let mut has_unknown_codes = false;
+
+        // Codes in a `noqa` comment may belong to the linter, and codes in a
+        // `type: ignore` comment to another type checker; only `knot: ignore`
+        // codes are guaranteed to be ours and can be validated.
+        if suppression.kind == SuppressionKind::KnotIgnore {
+            for (code, range) in &suppression.codes {
+                if Rule::from_name(code).is_none() {
+                    has_unknown_codes = true;
+                    diagnostics.push(TypeCheckDiagnostic {
+                        file,
+                        severity: Rule::UnknownRule.default_severity(),
+                        rule: Rule::UnknownRule,
+                        message: format!("Unknown rule `{code}`"),
+                        range: *range,
+                    });
+                }
+            }
+        }
+
+        // An unknown code already explains why the suppression didn't work; and a
+        // `noqa` that suppresses nothing most likely targets a linter diagnostic.
+        if !suppression.used && !has_unknown_codes && suppression.kind != SuppressionKind::Noqa {
+            diagnostics.push(TypeCheckDiagnostic {
+                file,
+                severity: Rule::UnusedIgnore.default_severity(),
+                rule: Rule::UnusedIgnore,
+                message: format!("Unused `{}` comment", suppression.kind),
+                range: suppression.range,
+            });
+        }
+    }
+}
+
+/// The syntax a suppression comment was written with.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+enum SuppressionKind {
+    /// `# type: ignore`, the PEP 484 syntax shared with other type checkers
+    TypeIgnore,
+    /// `# knot: ignore`, our own suppression syntax
+    KnotIgnore,
+    /// `# noqa`, ruff's linter suppression syntax
+    Noqa,
+}
+
+impl std::fmt::Display for SuppressionKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            SuppressionKind::TypeIgnore => "type: ignore",
+            SuppressionKind::KnotIgnore => "knot: ignore",
+            SuppressionKind::Noqa => "noqa",
+        })
+    }
+}
+
+/// A single suppression comment.
+struct Suppression<'a> {
+    kind: SuppressionKind,
+
+    /// The rule codes listed in the comment together with their source ranges;
+    /// empty for a bare suppression, which suppresses every rule.
+    codes: Vec<(&'a str, TextRange)>,
+
+    /// The line the comment (and therefore every diagnostic it suppresses) is on.
+    line: OneIndexed,
+
+    range: TextRange,
+    used: bool,
+}
+
+impl Suppression<'_> {
+    fn suppresses(&self, rule: &str) -> bool {
+        self.codes.is_empty() || self.codes.iter().any(|(code, _)| *code == rule)
+    }
+}
+
+/// Parses `text` (including the leading `#`) as a suppression comment, returning the
+/// comment's kind and its rule codes (with ranges relative to the start of `text`), or
+/// `None` if it isn't a suppression comment.
+///
+/// `type: ignore` and `knot: ignore` list their codes in square brackets
+/// (`# knot: ignore[invalid-assignment]`), `noqa` after a colon (`# noqa: E501`,
+/// case-insensitively, matching the linter).
+fn parse_suppression_comment(text: &str) -> Option<(SuppressionKind, Vec<(&str, TextRange)>)> {
+    let without_hashes = text.trim_start_matches('#');
+    let content = without_hashes.trim_start();
+    let content_offset = text.len() - content.len();
+
+    if let Some(rest) = content.strip_prefix("type: ignore") {
+        let codes = parse_bracketed_codes(rest, content_offset + "type: ignore".len())?;
+        Some((SuppressionKind::TypeIgnore, codes))
+    } else if let Some(rest) = content.strip_prefix("knot: ignore") {
+        let codes = parse_bracketed_codes(rest, content_offset + "knot: ignore".len())?;
+        Some((SuppressionKind::KnotIgnore, codes))
+    } else if content
+        .get(..4)
+        .is_some_and(|prefix| prefix.eq_ignore_ascii_case("noqa"))
+    {
+        let rest = &content[4..];
+        if let Some(codes) = rest.strip_prefix(':') {
+            Some((
+                SuppressionKind::Noqa,
+                split_codes(codes, content_offset + "noqa:".len()),
+            ))
+        } else if rest.is_empty() || rest.starts_with(char::is_whitespace) {
+            Some((SuppressionKind::Noqa, Vec::new()))
+        } else {
+            // e.g. `# noqank` is not a suppression.
+            None
+        }
+    } else {
+        None
+    }
+}
+
+/// Parses the `[code, ...]` tail of a `type: ignore` or `knot: ignore` comment.
+/// `offset` is the position of `rest` within the comment.
+fn parse_bracketed_codes(rest: &str, offset: usize) -> Option<Vec<(&str, TextRange)>> {
+    if let Some(in_brackets) = rest.strip_prefix('[') {
+        let (codes, _) = in_brackets.split_once(']')?;
+        Some(split_codes(codes, offset + 1))
+    } else if rest.is_empty() || rest.starts_with(char::is_whitespace) {
+        Some(Vec::new())
+    } else {
+        // e.g. `# type: ignoreme` is not a suppression.
+        None
+    }
+}
+
+/// Splits a comma-separated code list, recording each code's range within the comment.
+/// `offset` is the position of `codes` within the comment.
+fn split_codes(codes: &str, offset: usize) -> Vec<(&str, TextRange)> {
+    let mut result = Vec::new();
+    let mut position = offset;
+
+    for part in codes.split(',') {
+        let code = part.trim();
+        if !code.is_empty() {
+            let start = position + (part.len() - part.trim_start().len());
+            result.push((
+                code,
+                TextRange::at(
+                    TextSize::try_from(start).expect("comment to be shorter than 4GB"),
+                    TextSize::try_from(code.len()).expect("comment to be shorter than 4GB"),
+                ),
+            ));
+        }
+        position += part.len() + ','.len_utf8();
+    }
+
+    result
+}
@@ -10,25 +10,26 @@ pub(crate) struct OutOfBoundsError;
 pub(crate) trait PyIndex {
     type Item;
 
-    fn py_index(&mut self, index: i32) -> Result<Self::Item, OutOfBoundsError>;
+    fn py_index(&mut self, index: i64) -> Result<Self::Item, OutOfBoundsError>;
 }
 
-fn from_nonnegative_i32(index: i32) -> usize {
-    static_assertions::const_assert!(usize::BITS >= 32);
+fn from_nonnegative_i64(index: i64) -> usize {
     debug_assert!(index >= 0);
 
-    usize::try_from(index)
-        .expect("Should only ever pass a positive integer to `from_nonnegative_i32`")
+    usize::try_from(index).unwrap_or({
+        // On platforms where usize is smaller than 64 bits, saturate to
+        // usize::MAX: such an index is past the end of any iterator whose
+        // elements fit in memory.
+        usize::MAX
+    })
 }
 
-fn from_negative_i32(index: i32) -> usize {
-    static_assertions::const_assert!(usize::BITS >= 32);
-
-    index.checked_neg().map(from_nonnegative_i32).unwrap_or({
-        // 'checked_neg' only fails for i32::MIN. We can not
-        // represent -i32::MIN as a i32, but we can represent
-        // it as a usize, since usize is at least 32 bits.
-        from_nonnegative_i32(i32::MAX) + 1
+fn from_negative_i64(index: i64) -> usize {
+    index.checked_neg().map(from_nonnegative_i64).unwrap_or({
+        // 'checked_neg' only fails for i64::MIN. We can not represent
+        // -i64::MIN as a i64, so saturate in the same way as
+        // `from_nonnegative_i64` does.
+        from_nonnegative_i64(i64::MAX).saturating_add(1)
     })
 }
 
@@ -45,11 +46,11 @@ enum Nth {
 }
 
 impl Nth {
-    fn from_index(index: i32) -> Self {
+    fn from_index(index: i64) -> Self {
         if index >= 0 {
-            Nth::FromStart(from_nonnegative_i32(index))
+            Nth::FromStart(from_nonnegative_i64(index))
         } else {
-            Nth::FromEnd(from_negative_i32(index) - 1)
+            Nth::FromEnd(from_negative_i64(index) - 1)
         }
     }
 
@@ -81,7 +82,7 @@ where
 {
     type Item = I;
 
-    fn py_index(&mut self, index: i32) -> Result<I, OutOfBoundsError> {
+    fn py_index(&mut self, index: i64) -> Result<I, OutOfBoundsError> {
         match Nth::from_index(index) {
             Nth::FromStart(nth) => self.nth(nth).ok_or(OutOfBoundsError),
             Nth::FromEnd(nth_rev) => self.nth_back(nth_rev).ok_or(OutOfBoundsError),
@@ -104,6 +105,20 @@ pub(crate) trait PySlice {
         Either<impl Iterator<Item = &Self::Item>, impl Iterator<Item = &Self::Item>>,
         StepSizeZeroError,
     >;
+
+    /// Like [`py_slice`](PySlice::py_slice), but yields owned copies of the
+    /// elements instead of references.
+    fn py_slice_owned(
+        &self,
+        start: Option<i32>,
+        stop: Option<i32>,
+        step: Option<i32>,
+    ) -> Result<
+        Either<impl Iterator<Item = Self::Item>, impl Iterator<Item = Self::Item>>,
+        StepSizeZeroError,
+    >
+    where
+        Self::Item: Copy;
 }
 
 impl<T> PySlice for [T] {
@@ -131,10 +146,10 @@ impl<T> PySlice for [T] {
             return Ok(Either::Left(self.iter().skip(0).take(0).step_by(1)));
         }
 
-        let to_position = |index| Nth::from_index(index).to_position(len);
+        let to_position = |index| Nth::from_index(i64::from(index)).to_position(len);
 
         if step_int.is_positive() {
-            let step = from_nonnegative_i32(step_int);
+            let step = from_nonnegative_i64(i64::from(step_int));
 
             let start = start.map(to_position).unwrap_or(Position::BeforeStart);
             let stop = stop.map(to_position).unwrap_or(Position::AfterEnd);
@@ -161,7 +176,7 @@ impl<T> PySlice for [T] {
                 self.iter().skip(skip).take(take).step_by(step),
             ))
         } else {
-            let step = from_negative_i32(step_int);
+            let step = from_negative_i64(i64::from(step_int));
 
             let start = start.map(to_position).unwrap_or(Position::AfterEnd);
             let stop = stop.map(to_position).unwrap_or(Position::BeforeStart);
@@ -189,6 +204,22 @@ impl<T> PySlice for [T] {
             ))
         }
     }
+
+    fn py_slice_owned(
+        &self,
+        start: Option<i32>,
+        stop: Option<i32>,
+        step: Option<i32>,
+    ) -> Result<
+        Either<impl Iterator<Item = Self::Item>, impl Iterator<Item = Self::Item>>,
+        StepSizeZeroError,
+    >
+    where
+        T: Copy,
+    {
+        self.py_slice(start, stop, step)
+            .map(|iter| iter.map_either(|forward| forward.copied(), |reverse| reverse.copied()))
+    }
 }
 
 #[cfg(test)]
@@ -206,8 +237,8 @@ mod tests {
         assert_eq!(iter.clone().py_index(0), Err(OutOfBoundsError));
         assert_eq!(iter.clone().py_index(1), Err(OutOfBoundsError));
         assert_eq!(iter.clone().py_index(-1), Err(OutOfBoundsError));
-        assert_eq!(iter.clone().py_index(i32::MIN), Err(OutOfBoundsError));
-        assert_eq!(iter.clone().py_index(i32::MAX), Err(OutOfBoundsError));
+        assert_eq!(iter.clone().py_index(i64::MIN), Err(OutOfBoundsError));
+        assert_eq!(iter.clone().py_index(i64::MAX), Err(OutOfBoundsError));
     }
 
     #[test]
@@ -240,13 +271,32 @@ mod tests {
         let iter = 0..=u32::MAX;
 
         // u32::MAX - |i32::MIN| + 1 = 2^32 - 1 - 2^31 + 1 = 2^31
-        assert_eq!(iter.clone().py_index(i32::MIN), Ok(2u32.pow(31)));
+        assert_eq!(iter.clone().py_index(i64::from(i32::MIN)), Ok(2u32.pow(31)));
         assert_eq!(iter.clone().py_index(-2), Ok(u32::MAX - 2 + 1));
         assert_eq!(iter.clone().py_index(-1), Ok(u32::MAX - 1 + 1));
 
         assert_eq!(iter.clone().py_index(0), Ok(0));
         assert_eq!(iter.clone().py_index(1), Ok(1));
-        assert_eq!(iter.clone().py_index(i32::MAX), Ok(i32::MAX as u32));
+        assert_eq!(
+            iter.clone().py_index(i64::from(i32::MAX)),
+            Ok(i32::MAX as u32)
+        );
+    }
+
+    #[test]
+    fn py_index_beyond_i32_range() {
+        let iter = ['a', 'b', 'c'].into_iter();
+
+        assert_eq!(
+            iter.clone().py_index(i64::from(i32::MAX) + 1),
+            Err(OutOfBoundsError)
+        );
+        assert_eq!(
+            iter.clone().py_index(i64::from(i32::MIN) - 1),
+            Err(OutOfBoundsError)
+        );
+        assert_eq!(iter.clone().py_index(i64::MAX), Err(OutOfBoundsError));
+        assert_eq!(iter.clone().py_index(i64::MIN), Err(OutOfBoundsError));
     }
 
     #[track_caller]
@@ -258,6 +308,10 @@ mod tests {
         expected: &[char; M],
     ) {
         assert_equal(input.py_slice(start, stop, step).unwrap(), expected.iter());
+        assert_equal(
+            input.py_slice_owned(start, stop, step).unwrap(),
+            expected.iter().copied(),
+        );
     }
 
     #[test]
@@ -439,6 +493,10 @@ mod tests {
             input.py_slice(Some(0), Some(0), Some(0)),
             Err(StepSizeZeroError)
         ));
+        assert!(matches!(
+            input.py_slice_owned(None, None, Some(0)),
+            Err(StepSizeZeroError)
+        ));
 
         assert_eq_slice(&input, Some(0), Some(8), Some(2), &['a', 'c', 'e', 'g']);
         assert_eq_slice(&input, Some(0), Some(7), Some(2), &['a', 'c', 'e', 'g']);
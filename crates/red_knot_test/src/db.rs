@@ -17,6 +17,13 @@ pub(crate) struct Db {
 
 impl Db {
     pub(crate) fn setup(workspace_root: SystemPathBuf) -> Self {
+        Self::setup_with_target_version(workspace_root, PythonVersion::default())
+    }
+
+    pub(crate) fn setup_with_target_version(
+        workspace_root: SystemPathBuf,
+        target_version: PythonVersion,
+    ) -> Self {
         let db = Self {
             workspace_root,
             storage: salsa::Storage::default(),
@@ -32,7 +39,8 @@ impl Db {
         Program::from_settings(
             &db,
             &ProgramSettings {
-                target_version: PythonVersion::default(),
+                target_version,
+                respect_noqa: false,
                 search_paths: SearchPathSettings::new(db.workspace_root.clone()),
             },
         )
@@ -14,6 +14,9 @@ mod db;
 mod diagnostic;
 mod matcher;
 mod parser;
+mod snippet;
+
+pub use snippet::{check_snippet, SnippetFailure, SnippetReport, SnippetSettings};
 
 /// Run `path` as a markdown test suite with given `title`.
 ///
@@ -0,0 +1,180 @@
+//! Check a single Python snippet with inline assertions against the semantic model.
+//!
+//! This exposes the core of the markdown-test runner as a reusable API: downstream users
+//! (and our own integration tests) can write a small Python snippet with inline `# revealed:` /
+//! `# error:` expectations and run it against the semantic DB without duplicating the harness.
+//!
+//! The assertion grammar is the same one used by the markdown test suites:
+//!
+//! - `# revealed: <type>` asserts that a `revealed-type` diagnostic with the message
+//!   ``Revealed type is `<type>` `` is emitted on this line.
+//! - `# error: [<rule>]` asserts that a diagnostic with the given rule is emitted on this line.
+//! - `# error: "<substring>"` asserts that a diagnostic whose message contains `<substring>`
+//!   is emitted on this line.
+//! - `# error: <column> [<rule>] "<substring>"` combines a one-based column number with the
+//!   rule and message-substring filters; both the rule and the substring are optional, but at
+//!   least one of them must be present.
+//!
+//! An assertion written on its own (comment-only) line applies to the next non-comment line.
+//! Every diagnostic must be matched by an assertion and vice versa; any mismatch is reported
+//! in the returned [`SnippetReport`] with the line it occurred on.
+use crate::db::Db;
+use crate::matcher;
+use red_knot_python_semantic::types::check_types;
+use red_knot_python_semantic::PythonVersion;
+use ruff_db::diagnostic::{Diagnostic, ParseDiagnostic};
+use ruff_db::files::system_path_to_file;
+use ruff_db::parsed::parsed_module;
+use ruff_db::system::{DbWithTestSystem, SystemPathBuf};
+use ruff_source_file::OneIndexed;
+use std::fmt;
+
+/// Settings controlling how [`check_snippet`] runs a snippet.
+#[derive(Debug, Clone, Default)]
+pub struct SnippetSettings {
+    /// The target Python version the snippet is checked against.
+    pub target_version: PythonVersion,
+}
+
+/// The outcome of running [`check_snippet`]: all assertion/diagnostic mismatches, by line.
+#[derive(Debug)]
+pub struct SnippetReport {
+    failures: Vec<SnippetFailure>,
+}
+
+impl SnippetReport {
+    /// Return `true` if every diagnostic was matched by an assertion and vice versa.
+    pub fn is_ok(&self) -> bool {
+        self.failures.is_empty()
+    }
+
+    /// The mismatches between assertions and emitted diagnostics, in ascending line order.
+    pub fn failures(&self) -> &[SnippetFailure] {
+        &self.failures
+    }
+}
+
+impl fmt::Display for SnippetReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for failure in &self.failures {
+            for message in &failure.messages {
+                writeln!(f, "{}: {message}", failure.line_number)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A group of assertion/diagnostic mismatches on a single line of the snippet.
+#[derive(Debug)]
+pub struct SnippetFailure {
+    /// The one-based line number in the snippet the mismatches occurred on.
+    pub line_number: OneIndexed,
+    /// Human-readable descriptions of each mismatch (an unmatched assertion,
+    /// or an emitted diagnostic no assertion matched).
+    pub messages: Vec<String>,
+}
+
+/// Type-check `source` and match the emitted diagnostics against its inline assertions.
+///
+/// See the [module-level documentation](self) for the assertion grammar.
+pub fn check_snippet(source: &str, settings: &SnippetSettings) -> SnippetReport {
+    colored::control::set_override(false);
+
+    let mut db = Db::setup_with_target_version(SystemPathBuf::from("/src"), settings.target_version);
+    db.write_file("/src/snippet.py", source).unwrap();
+    let file = system_path_to_file(&db, "/src/snippet.py").unwrap();
+
+    let parsed = parsed_module(&db, file);
+
+    let mut diagnostics: Vec<Box<dyn Diagnostic>> = parsed
+        .errors()
+        .iter()
+        .cloned()
+        .map(|error| {
+            let diagnostic: Box<dyn Diagnostic> = Box::new(ParseDiagnostic::new(file, error));
+            diagnostic
+        })
+        .collect();
+
+    let type_diagnostics = check_types(&db, file);
+    diagnostics.extend(type_diagnostics.into_iter().map(|diagnostic| {
+        let diagnostic: Box<dyn Diagnostic> = Box::new((*diagnostic).clone());
+        diagnostic
+    }));
+
+    let failures = match matcher::match_file(&db, file, diagnostics) {
+        Ok(()) => Vec::new(),
+        Err(failures) => failures
+            .iter()
+            .map(|(line_number, messages)| SnippetFailure {
+                line_number,
+                messages: messages.to_vec(),
+            })
+            .collect(),
+    };
+
+    SnippetReport { failures }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{check_snippet, SnippetSettings};
+    use ruff_python_trivia::textwrap::dedent;
+    use ruff_source_file::OneIndexed;
+
+    #[test]
+    fn matching_assertions() {
+        let source = dedent(
+            r#"
+            x = 1
+            reveal_type(x)  # revealed: Literal[1]
+            y: int = "foo"  # error: [invalid-assignment]
+            "#,
+        );
+
+        let report = check_snippet(&source, &SnippetSettings::default());
+
+        assert!(report.is_ok(), "{report}");
+    }
+
+    #[test]
+    fn failed_assertion_reports_precise_diff() {
+        let report = check_snippet("reveal_type(1)  # revealed: str", &SnippetSettings::default());
+
+        assert!(!report.is_ok());
+        let failures = report.failures();
+        assert_eq!(failures.len(), 1);
+        assert_eq!(failures[0].line_number, OneIndexed::from_zero_indexed(0));
+        assert_eq!(
+            failures[0].messages,
+            vec![
+                "unmatched assertion: revealed: str".to_string(),
+                r#"unexpected error: 1 [revealed-type] "Revealed type is `Literal[1]`""#.to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn unexpected_diagnostic_is_reported() {
+        let source = dedent(
+            r#"
+            x = 1
+            y: str = x
+            "#,
+        );
+
+        let report = check_snippet(&source, &SnippetSettings::default());
+
+        assert!(!report.is_ok());
+        let failures = report.failures();
+        assert_eq!(failures.len(), 1);
+        assert_eq!(failures[0].line_number, OneIndexed::from_zero_indexed(2));
+        assert_eq!(failures[0].messages.len(), 1);
+        assert!(
+            failures[0].messages[0].contains("[invalid-assignment]"),
+            "{}",
+            failures[0].messages[0]
+        );
+    }
+}
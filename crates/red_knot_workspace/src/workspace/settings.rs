@@ -22,6 +22,7 @@ impl WorkspaceSettings {
 #[cfg_attr(test, derive(serde::Serialize))]
 pub struct Configuration {
     pub target_version: Option<PythonVersion>,
+    pub respect_noqa: Option<bool>,
     pub search_paths: SearchPathConfiguration,
 }
 
@@ -29,6 +30,7 @@ impl Configuration {
     /// Extends this configuration by using the values from `with` for all values that are absent in `self`.
     pub fn extend(&mut self, with: Configuration) {
         self.target_version = self.target_version.or(with.target_version);
+        self.respect_noqa = self.respect_noqa.or(with.respect_noqa);
         self.search_paths.extend(with.search_paths);
     }
 
@@ -40,6 +42,7 @@ impl Configuration {
         WorkspaceSettings {
             program: ProgramSettings {
                 target_version: self.target_version.unwrap_or_default(),
+                respect_noqa: self.respect_noqa.unwrap_or_default(),
                 search_paths: self.search_paths.to_settings(workspace_root),
             },
         }
@@ -29,9 +29,10 @@ pub trait Diagnostic: Send + Sync + std::fmt::Debug {
     }
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Severity {
     Info,
+    Warning,
     Error,
 }
 
@@ -50,6 +51,7 @@ impl std::fmt::Display for DisplayDiagnostic<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self.diagnostic.severity() {
             Severity::Info => f.write_str("info")?,
+            Severity::Warning => f.write_str("warning")?,
             Severity::Error => f.write_str("error")?,
         }
 
@@ -46,6 +46,7 @@ impl ModuleDb {
             &db,
             &ProgramSettings {
                 target_version,
+                respect_noqa: false,
                 search_paths,
             },
         )?;